@@ -1,11 +1,56 @@
 use wasm_bindgen::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 use ark_bn254::{Bn254, Fr};
-use ark_groth16::{Groth16, Proof};
+use ark_ff::PrimeField;
+use ark_groth16::{Groth16, Proof, ProvingKey, VerifyingKey};
 use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+use ark_std::rand::rngs::OsRng;
 use ark_r1cs_std::fields::fp::FpVar;
 use ark_r1cs_std::prelude::*;
 
+/// Scalar field of the BN254 curve every circuit in this crate is defined
+/// over.
+type F = Fr;
+
+/// Bit-width of the `age_diff = (current_year - birth_year) - min_age` range
+/// proof. `age_diff` is proven to lie in `[0, 2^AGE_DIFF_BITS)`, covering age
+/// differences up to 255 years — comfortably more than any real person's age
+/// — while ruling out the prime-field wraparound a direct `enforce_cmp`
+/// comparison is vulnerable to.
+pub const AGE_DIFF_BITS: usize = 8;
+
+/// Bit-width `birth_year`/`current_year` are range-checked to. Without this,
+/// the subtraction `current_year - birth_year` could be gamed by choosing a
+/// year near the field modulus so it wraps into a small-looking difference.
+/// 20 bits covers any year up to ~1,000,000, far beyond any plausible input.
+pub const YEAR_BITS: usize = 20;
+
+/// Bit-width a balance is range-checked to before the threshold comparison.
+/// 64 bits covers any `u64` balance.
+pub const BALANCE_BITS: usize = 64;
+
+/// Bit-width of the `balance - min_balance` range proof, for the same
+/// wraparound-avoidance reason as `AGE_DIFF_BITS`.
+pub const BALANCE_DIFF_BITS: usize = 64;
+
+/// Fixed size every `country_membership` circuit's committed list is padded
+/// to, so every proof of this `circuit_type` shares the same constraint
+/// system regardless of how many countries the caller actually authorizes.
+/// Callers with fewer entries pad by repeating the list's first member,
+/// which doesn't change the set `country_code` can match.
+pub const MAX_COUNTRY_LIST_LEN: usize = 8;
+
+/// `circuit_type` string for [`build_age_threshold_circuit`], matching the
+/// value issuers are authorized under and the CosmWasm contract's
+/// `RegisterCircuit` registers circuits as.
+pub const AGE_THRESHOLD_CIRCUIT_TYPE: &str = "age_threshold";
+/// `circuit_type` string for the generic [`ThresholdCircuit`] instantiated
+/// with `ComparisonOp::GreaterOrEqual` over a balance.
+pub const BALANCE_THRESHOLD_CIRCUIT_TYPE: &str = "balance_threshold";
+/// `circuit_type` string for [`build_country_membership_circuit`].
+pub const COUNTRY_MEMBERSHIP_CIRCUIT_TYPE: &str = "country_membership";
+
 // When the `wee_alloc` feature is enabled, use `wee_alloc` as the global
 // allocator.
 #[cfg(feature = "wee_alloc")]
@@ -27,149 +72,429 @@ macro_rules! console_log {
     ($($t:tt)*) => (log(&format_args!($($t)*).to_string()))
 }
 
-#[derive(Serialize, Deserialize)]
-pub struct AgeVerificationInputs {
-    pub birth_year: u32,
-    pub current_year: u32,
-    pub min_age: u32,
+/// Which comparison a threshold circuit proves between `secret_value` and
+/// `public_threshold`. What [`ThresholdCircuit`] and
+/// [`build_age_threshold_circuit`] are parameterized by.
+#[derive(Clone, Copy)]
+pub enum ComparisonOp {
+    GreaterOrEqual,
+    LessOrEqual,
+    Equal,
 }
 
-#[derive(Serialize, Deserialize)]
-pub struct ProofResult {
-    pub proof: String,
-    pub public_inputs: Vec<String>,
+/// Enforce `lhs {op} rhs`, reusing [`enforce_bounded_nonnegative`] for the
+/// inequality directions the same way the original age-only circuit did for
+/// its `age_diff >= 0` check: a bounded difference has a valid `diff_bits`
+/// decomposition only if it's actually non-negative, which is what each
+/// inequality reduces to.
+pub fn enforce_threshold(
+    cs: ConstraintSystemRef<F>,
+    lhs: &FpVar<F>,
+    rhs: &FpVar<F>,
+    op: ComparisonOp,
+    diff_bits: usize,
+) -> Result<(), SynthesisError> {
+    match op {
+        ComparisonOp::GreaterOrEqual => enforce_bounded_nonnegative(cs, &(lhs - rhs), diff_bits),
+        ComparisonOp::LessOrEqual => enforce_bounded_nonnegative(cs, &(rhs - lhs), diff_bits),
+        ComparisonOp::Equal => lhs.enforce_equal(rhs),
+    }
+}
+
+/// Enforce that `value` equals one of `members` via the standard
+/// product-of-differences set-inclusion gadget: `(value - members[0]) *
+/// (value - members[1]) * ...` can only be zero if `value` matches at
+/// least one member, since a prime field has no zero divisors.
+pub fn enforce_membership(value: &FpVar<F>, members: &[FpVar<F>]) -> Result<(), SynthesisError> {
+    let mut product = value - &members[0];
+    for member in &members[1..] {
+        product *= value - member;
+    }
+    product.enforce_equal(&FpVar::<F>::zero())
+}
+
+/// Enforce that `value` is a member of `[0, 2^bits)` by decomposing it into
+/// `bits` boolean witnesses (each allocated via [`Boolean::new_witness`],
+/// which itself enforces `b*(b-1)=0`) and constraining their little-endian
+/// weighted sum to reconstruct `value`. A value outside `[0, 2^bits)` —
+/// including every value a "negative" field subtraction would wrap into —
+/// has no valid decomposition of this width, so the reconstruction
+/// constraint cannot be satisfied.
+///
+/// Exposed so every threshold circuit in this crate shares one range-proof
+/// implementation instead of each re-deriving it.
+pub fn enforce_bounded_nonnegative(
+    cs: ConstraintSystemRef<F>,
+    value: &FpVar<F>,
+    bits: usize,
+) -> Result<(), SynthesisError> {
+    let value_bits = value.value().ok().map(|v| v.into_bigint().to_bits_le());
+
+    let bit_vars = (0..bits)
+        .map(|i| {
+            let bit_witness = value_bits.as_ref().map(|b| *b.get(i).unwrap_or(&false));
+            Boolean::new_witness(cs.clone(), || {
+                bit_witness.ok_or(SynthesisError::AssignmentMissing)
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let reconstructed = Boolean::le_bits_to_fp_var(&bit_vars)?;
+    reconstructed.enforce_equal(value)?;
+
+    Ok(())
+}
+
+/// Generic `secret_value {op} public_threshold` circuit, range-checking
+/// both witnesses to `value_bits` before the comparison so `op` can't be
+/// satisfied by prime-field wraparound. [`BALANCE_THRESHOLD_CIRCUIT_TYPE`]
+/// uses this directly with `op: GreaterOrEqual`; age-over-threshold needs
+/// two private inputs to derive its `secret_value` first, so it has its own
+/// circuit ([`AgeThresholdCircuit`]) that calls [`enforce_threshold`] the
+/// same way this one does.
+pub struct ThresholdCircuit {
+    pub secret_value: Option<F>,
+    pub public_threshold: Option<F>,
+    pub op: ComparisonOp,
+    pub value_bits: usize,
+    pub diff_bits: usize,
+}
+
+impl ConstraintSynthesizer<F> for ThresholdCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let secret_value = FpVar::new_witness(cs.clone(), || {
+            self.secret_value.ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        let public_threshold = FpVar::new_input(cs.clone(), || {
+            self.public_threshold.ok_or(SynthesisError::AssignmentMissing)
+        })?;
+
+        enforce_bounded_nonnegative(cs.clone(), &secret_value, self.value_bits)?;
+        enforce_bounded_nonnegative(cs.clone(), &public_threshold, self.value_bits)?;
+
+        enforce_threshold(cs, &secret_value, &public_threshold, self.op, self.diff_bits)
+    }
 }
 
-// Age verification circuit
-pub struct AgeVerificationCircuit {
+/// Age-over-threshold circuit: proves `(current_year - birth_year) >=
+/// min_age` without revealing `birth_year`. Kept as its own
+/// `ConstraintSynthesizer` (rather than an instantiation of
+/// [`ThresholdCircuit`]) because its `secret_value` — the age — is derived
+/// from two private/public inputs instead of being supplied directly, but it
+/// still range-checks its raw inputs via [`enforce_bounded_nonnegative`] and
+/// delegates the final comparison to [`enforce_threshold`], the same shared
+/// gadgets every other circuit in this crate uses.
+pub struct AgeThresholdCircuit {
     pub birth_year: Option<F>,
     pub current_year: Option<F>,
     pub min_age: Option<F>,
 }
 
-impl ConstraintSynthesizer<F> for AgeVerificationCircuit {
+/// Build an [`AgeThresholdCircuit`] witness/public-input assignment.
+/// `current_year` and `min_age` are public; `birth_year` stays private.
+pub fn build_age_threshold_circuit(
+    birth_year: Option<F>,
+    current_year: Option<F>,
+    min_age: Option<F>,
+) -> AgeThresholdCircuit {
+    AgeThresholdCircuit { birth_year, current_year, min_age }
+}
+
+impl ConstraintSynthesizer<F> for AgeThresholdCircuit {
     fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
-        // Allocate private inputs
         let birth_year = FpVar::new_witness(cs.clone(), || {
             self.birth_year.ok_or(SynthesisError::AssignmentMissing)
         })?;
-        
         let current_year = FpVar::new_input(cs.clone(), || {
             self.current_year.ok_or(SynthesisError::AssignmentMissing)
         })?;
-        
         let min_age = FpVar::new_input(cs.clone(), || {
             self.min_age.ok_or(SynthesisError::AssignmentMissing)
         })?;
-        
-        // Calculate age: current_year - birth_year
+
+        // Range-check the years themselves so the subtraction below can't be
+        // gamed by picking a year near the field modulus that wraps into a
+        // small-looking difference.
+        enforce_bounded_nonnegative(cs.clone(), &birth_year, YEAR_BITS)?;
+        enforce_bounded_nonnegative(cs.clone(), &current_year, YEAR_BITS)?;
+
         let age = &current_year - &birth_year;
-        
-        // Constraint: age >= min_age
-        // This is implemented as age - min_age >= 0
-        let age_diff = &age - &min_age;
-        
-        // For simplicity, we'll use a range check here
-        // In a real implementation, you'd want more sophisticated range proofs
-        let zero = FpVar::constant(Fr::zero());
-        age_diff.enforce_cmp(&zero, std::cmp::Ordering::Greater, true)?;
-        
-        Ok(())
+
+        enforce_threshold(cs, &age, &min_age, ComparisonOp::GreaterOrEqual, AGE_DIFF_BITS)
+    }
+}
+
+/// Country-membership circuit: proves `country_code` is one of
+/// `allowed_countries` without revealing which one. `allowed_countries` is
+/// public (the verifier already knows the committed list; only the match is
+/// hidden), always exactly [`MAX_COUNTRY_LIST_LEN`] entries long so every
+/// proof of this `circuit_type` shares one constraint system.
+pub struct CountryMembershipCircuit {
+    pub country_code: Option<F>,
+    pub allowed_countries: [Option<F>; MAX_COUNTRY_LIST_LEN],
+}
+
+/// Build a [`CountryMembershipCircuit`], padding `allowed_countries` up to
+/// [`MAX_COUNTRY_LIST_LEN`] by repeating its first entry if it's shorter.
+/// Panics if `allowed_countries` is empty or longer than
+/// `MAX_COUNTRY_LIST_LEN`; callers validate this before constructing the
+/// circuit (see [`generate_proof`]).
+pub fn build_country_membership_circuit(
+    country_code: Option<F>,
+    allowed_countries: &[F],
+) -> CountryMembershipCircuit {
+    assert!(
+        allowed_countries.len() <= MAX_COUNTRY_LIST_LEN,
+        "allowed_countries has more than MAX_COUNTRY_LIST_LEN entries"
+    );
+    let first = allowed_countries[0];
+    let mut padded = [Some(first); MAX_COUNTRY_LIST_LEN];
+    for (slot, value) in padded.iter_mut().zip(allowed_countries.iter()) {
+        *slot = Some(*value);
+    }
+    CountryMembershipCircuit { country_code, allowed_countries: padded }
+}
+
+impl ConstraintSynthesizer<F> for CountryMembershipCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let country_code = FpVar::new_witness(cs.clone(), || {
+            self.country_code.ok_or(SynthesisError::AssignmentMissing)
+        })?;
+
+        let members = self
+            .allowed_countries
+            .into_iter()
+            .map(|member| FpVar::new_input(cs.clone(), || member.ok_or(SynthesisError::AssignmentMissing)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        enforce_membership(&country_code, &members)
     }
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct AgeThresholdInputs {
+    pub birth_year: u32,
+    pub current_year: u32,
+    pub min_age: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct BalanceThresholdInputs {
+    pub balance: u64,
+    pub min_balance: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CountryMembershipInputs {
+    pub country_code: u32,
+    pub allowed_countries: Vec<u32>,
+}
+
+/// `circuit_spec_json` argument to [`generate_proof`]: which circuit to
+/// build and the proving key from that circuit's own trusted setup run (see
+/// [`setup_circuit`]). `proving_key` must come from a setup of the same
+/// `circuit_type` — the circuits differ in shape, so a mismatched key fails
+/// proof generation.
+#[derive(Serialize, Deserialize)]
+pub struct CircuitSpec {
+    pub circuit_type: String,
+    pub proving_key: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ProofResult {
+    pub proof: String,
+    pub public_inputs: Vec<String>,
+}
+
+/// Output of [`setup_circuit`] — the proving and verification keys from a
+/// single trusted setup run, base64-encoded so the bundle can be generated
+/// offline and shipped with the app instead of being regenerated (with fresh
+/// randomness, and therefore a mismatched `vk`) on every call.
+#[derive(Serialize, Deserialize)]
+pub struct CircuitKeys {
+    pub proving_key: String,
+    pub verifying_key: String,
+}
+
 #[wasm_bindgen]
 pub fn init_panic_hook() {
     console_error_panic_hook::set_once();
 }
 
+fn serialize_keys(pk: &ProvingKey<Bn254>, vk: &VerifyingKey<Bn254>) -> Result<(Vec<u8>, Vec<u8>), JsValue> {
+    use ark_serialize::CanonicalSerialize;
+
+    let mut pk_bytes = Vec::new();
+    pk.serialize_compressed(&mut pk_bytes)
+        .map_err(|e| JsValue::from_str(&format!("Proving key serialization failed: {:?}", e)))?;
+
+    let mut vk_bytes = Vec::new();
+    vk.serialize_compressed(&mut vk_bytes)
+        .map_err(|e| JsValue::from_str(&format!("Verification key serialization failed: {:?}", e)))?;
+
+    Ok((pk_bytes, vk_bytes))
+}
+
+/// Run the Groth16 trusted setup for `circuit_type` exactly once and return
+/// the resulting proving/verification keys as a base64 JSON bundle. Callers
+/// persist this bundle (generated offline) and pass its pieces into
+/// [`generate_proof`]/[`verify_proof`] so every prover and verifier of this
+/// `circuit_type` shares the same `vk` instead of each regenerating it with
+/// fresh randomness.
 #[wasm_bindgen]
-pub fn generate_age_proof(inputs_json: &str) -> Result<String, JsValue> {
-    let inputs: AgeVerificationInputs = serde_json::from_str(inputs_json)
-        .map_err(|e| JsValue::from_str(&format!("Invalid input JSON: {}", e)))?;
-    
-    // Convert inputs to field elements
-    let birth_year = F::from(inputs.birth_year as u64);
-    let current_year = F::from(inputs.current_year as u64);
-    let min_age = F::from(inputs.min_age as u64);
-    
-    // Create circuit
-    let circuit = AgeVerificationCircuit {
-        birth_year: Some(birth_year),
-        current_year: Some(current_year),
-        min_age: Some(min_age),
+pub fn setup_circuit(circuit_type: &str) -> Result<String, JsValue> {
+    let mut rng = OsRng;
+    let (pk_bytes, vk_bytes) = match circuit_type {
+        AGE_THRESHOLD_CIRCUIT_TYPE => {
+            let circuit = build_age_threshold_circuit(None, None, None);
+            let (pk, vk) = Groth16::<Bn254>::circuit_specific_setup(circuit, &mut rng)
+                .map_err(|e| JsValue::from_str(&format!("Setup failed: {:?}", e)))?;
+            serialize_keys(&pk, &vk)?
+        }
+        BALANCE_THRESHOLD_CIRCUIT_TYPE => {
+            let circuit = ThresholdCircuit {
+                secret_value: None,
+                public_threshold: None,
+                op: ComparisonOp::GreaterOrEqual,
+                value_bits: BALANCE_BITS,
+                diff_bits: BALANCE_DIFF_BITS,
+            };
+            let (pk, vk) = Groth16::<Bn254>::circuit_specific_setup(circuit, &mut rng)
+                .map_err(|e| JsValue::from_str(&format!("Setup failed: {:?}", e)))?;
+            serialize_keys(&pk, &vk)?
+        }
+        COUNTRY_MEMBERSHIP_CIRCUIT_TYPE => {
+            let circuit =
+                CountryMembershipCircuit { country_code: None, allowed_countries: [None; MAX_COUNTRY_LIST_LEN] };
+            let (pk, vk) = Groth16::<Bn254>::circuit_specific_setup(circuit, &mut rng)
+                .map_err(|e| JsValue::from_str(&format!("Setup failed: {:?}", e)))?;
+            serialize_keys(&pk, &vk)?
+        }
+        other => return Err(JsValue::from_str(&format!("Unknown circuit_type: {other}"))),
     };
-    
+
+    let keys = CircuitKeys { proving_key: base64::encode(pk_bytes), verifying_key: base64::encode(vk_bytes) };
+
+    serde_json::to_string(&keys).map_err(|e| JsValue::from_str(&format!("Result serialization failed: {}", e)))
+}
+
+/// Build and prove the circuit named by `circuit_spec_json.circuit_type`,
+/// with witnesses drawn from `inputs_json` (shape depends on `circuit_type`:
+/// [`AgeThresholdInputs`], [`BalanceThresholdInputs`], or
+/// [`CountryMembershipInputs`]). Generates the proof against the
+/// caller-supplied, already-persisted proving key rather than regenerating
+/// one.
+#[wasm_bindgen]
+pub fn generate_proof(circuit_spec_json: &str, inputs_json: &str) -> Result<String, JsValue> {
+    use ark_serialize::CanonicalDeserialize;
+
+    let spec: CircuitSpec = serde_json::from_str(circuit_spec_json)
+        .map_err(|e| JsValue::from_str(&format!("Invalid circuit spec JSON: {}", e)))?;
+
+    let pk_bytes = base64::decode(&spec.proving_key)
+        .map_err(|e| JsValue::from_str(&format!("Invalid proving key encoding: {}", e)))?;
+    let pk = ProvingKey::<Bn254>::deserialize_compressed(&pk_bytes[..])
+        .map_err(|e| JsValue::from_str(&format!("Proving key deserialization failed: {:?}", e)))?;
+
     let mut rng = OsRng;
-    
-    // Generate proving key (in practice, this would be done offline)
-    let (pk, vk) = Groth16::<Bn254>::circuit_specific_setup(circuit, &mut rng)
-        .map_err(|e| JsValue::from_str(&format!("Setup failed: {:?}", e)))?;
-    
-    // Create circuit for proof generation
-    let proof_circuit = AgeVerificationCircuit {
-        birth_year: Some(birth_year),
-        current_year: Some(current_year),
-        min_age: Some(min_age),
+
+    let (proof, public_inputs): (Proof<Bn254>, Vec<String>) = match spec.circuit_type.as_str() {
+        AGE_THRESHOLD_CIRCUIT_TYPE => {
+            let inputs: AgeThresholdInputs = serde_json::from_str(inputs_json)
+                .map_err(|e| JsValue::from_str(&format!("Invalid input JSON: {}", e)))?;
+
+            let current_year = F::from(inputs.current_year as u64);
+            let min_age = F::from(inputs.min_age as u64);
+            let circuit =
+                build_age_threshold_circuit(Some(F::from(inputs.birth_year as u64)), Some(current_year), Some(min_age));
+
+            let proof = Groth16::<Bn254>::prove(&pk, circuit, &mut rng)
+                .map_err(|e| JsValue::from_str(&format!("Proof generation failed: {:?}", e)))?;
+            (proof, vec![current_year.to_string(), min_age.to_string()])
+        }
+        BALANCE_THRESHOLD_CIRCUIT_TYPE => {
+            let inputs: BalanceThresholdInputs = serde_json::from_str(inputs_json)
+                .map_err(|e| JsValue::from_str(&format!("Invalid input JSON: {}", e)))?;
+
+            let min_balance = F::from(inputs.min_balance);
+            let circuit = ThresholdCircuit {
+                secret_value: Some(F::from(inputs.balance)),
+                public_threshold: Some(min_balance),
+                op: ComparisonOp::GreaterOrEqual,
+                value_bits: BALANCE_BITS,
+                diff_bits: BALANCE_DIFF_BITS,
+            };
+
+            let proof = Groth16::<Bn254>::prove(&pk, circuit, &mut rng)
+                .map_err(|e| JsValue::from_str(&format!("Proof generation failed: {:?}", e)))?;
+            (proof, vec![min_balance.to_string()])
+        }
+        COUNTRY_MEMBERSHIP_CIRCUIT_TYPE => {
+            let inputs: CountryMembershipInputs = serde_json::from_str(inputs_json)
+                .map_err(|e| JsValue::from_str(&format!("Invalid input JSON: {}", e)))?;
+
+            if inputs.allowed_countries.is_empty() || inputs.allowed_countries.len() > MAX_COUNTRY_LIST_LEN {
+                return Err(JsValue::from_str(&format!(
+                    "allowed_countries must have 1..={} entries, got {}",
+                    MAX_COUNTRY_LIST_LEN,
+                    inputs.allowed_countries.len()
+                )));
+            }
+
+            let members: Vec<F> = inputs.allowed_countries.iter().map(|&c| F::from(c as u64)).collect();
+            let circuit = build_country_membership_circuit(Some(F::from(inputs.country_code as u64)), &members);
+            let public_inputs = circuit.allowed_countries.iter().map(|m| m.unwrap().to_string()).collect();
+
+            let proof = Groth16::<Bn254>::prove(&pk, circuit, &mut rng)
+                .map_err(|e| JsValue::from_str(&format!("Proof generation failed: {:?}", e)))?;
+            (proof, public_inputs)
+        }
+        other => return Err(JsValue::from_str(&format!("Unknown circuit_type: {other}"))),
     };
-    
-    // Generate proof
-    let proof = Groth16::<Bn254>::prove(&pk, proof_circuit, &mut rng)
-        .map_err(|e| JsValue::from_str(&format!("Proof generation failed: {:?}", e)))?;
-    
-    // Serialize proof and public inputs
-    let proof_bytes = proof.serialize_compressed()
+
+    let mut proof_bytes = Vec::new();
+    proof.serialize_compressed(&mut proof_bytes)
         .map_err(|e| JsValue::from_str(&format!("Proof serialization failed: {:?}", e)))?;
-    
-    let result = ProofResult {
-        proof: base64::encode(proof_bytes),
-        public_inputs: vec![
-            current_year.to_string(),
-            min_age.to_string(),
-        ],
-    };
-    
-    serde_json::to_string(&result)
-        .map_err(|e| JsValue::from_str(&format!("Result serialization failed: {}", e)))
+
+    let result = ProofResult { proof: base64::encode(proof_bytes), public_inputs };
+
+    serde_json::to_string(&result).map_err(|e| JsValue::from_str(&format!("Result serialization failed: {}", e)))
 }
 
+/// Verify a proof against `vk_json` and its public inputs. Circuit-agnostic
+/// — `Groth16::verify` only needs the verification key, the public inputs
+/// as field elements, and the proof, so this works for every `circuit_type`
+/// [`generate_proof`] can produce.
 #[wasm_bindgen]
-pub fn verify_age_proof(proof_json: &str, public_inputs_json: &str) -> Result<bool, JsValue> {
+pub fn verify_proof(vk_json: &str, proof_json: &str, public_inputs_json: &str) -> Result<bool, JsValue> {
+    use ark_serialize::CanonicalDeserialize;
+
+    let vk_bytes = base64::decode(vk_json)
+        .map_err(|e| JsValue::from_str(&format!("Invalid verification key encoding: {}", e)))?;
+    let vk = VerifyingKey::<Bn254>::deserialize_compressed(&vk_bytes[..])
+        .map_err(|e| JsValue::from_str(&format!("Verification key deserialization failed: {:?}", e)))?;
+
     let proof_result: ProofResult = serde_json::from_str(proof_json)
         .map_err(|e| JsValue::from_str(&format!("Invalid proof JSON: {}", e)))?;
-    
+
     let public_inputs: Vec<String> = serde_json::from_str(public_inputs_json)
         .map_err(|e| JsValue::from_str(&format!("Invalid public inputs JSON: {}", e)))?;
-    
-    // Deserialize proof
+
     let proof_bytes = base64::decode(&proof_result.proof)
         .map_err(|e| JsValue::from_str(&format!("Invalid proof encoding: {}", e)))?;
-    
     let proof = Proof::<Bn254>::deserialize_compressed(&proof_bytes[..])
         .map_err(|e| JsValue::from_str(&format!("Proof deserialization failed: {:?}", e)))?;
-    
-    // Convert public inputs to field elements
-    let current_year = F::from_str(&public_inputs[0])
-        .map_err(|e| JsValue::from_str(&format!("Invalid current_year: {:?}", e)))?;
-    let min_age = F::from_str(&public_inputs[1])
-        .map_err(|e| JsValue::from_str(&format!("Invalid min_age: {:?}", e)))?;
-    
-    let public_inputs_f = vec![current_year, min_age];
-    
-    // Create verification key (in practice, this would be stored/retrieved)
-    let empty_circuit = AgeVerificationCircuit {
-        birth_year: None,
-        current_year: None,
-        min_age: None,
-    };
-    
-    let mut rng = OsRng;
-    let (_, vk) = Groth16::<Bn254>::circuit_specific_setup(empty_circuit, &mut rng)
-        .map_err(|e| JsValue::from_str(&format!("Setup failed: {:?}", e)))?;
-    
-    // Verify proof
+
+    let public_inputs_f: Vec<F> = public_inputs
+        .iter()
+        .map(|s| F::from_str(s).map_err(|e| JsValue::from_str(&format!("Invalid public input {:?}: {:?}", s, e))))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Verify proof against the caller-supplied verification key — the same
+    // one returned by `setup_circuit`, not a freshly regenerated one.
     let result = Groth16::<Bn254>::verify(&vk, &public_inputs_f, &proof)
         .map_err(|e| JsValue::from_str(&format!("Verification failed: {:?}", e)))?;
-    
+
     Ok(result)
-}
\ No newline at end of file
+}
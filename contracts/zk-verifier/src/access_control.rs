@@ -1,15 +1,28 @@
-use cosmwasm_std::{DepsMut, Deps, Env, MessageInfo, Response, Addr, StdResult};
+use cosmwasm_std::{DepsMut, Deps, Env, MessageInfo, Response, Addr, StdResult, Order, CosmosMsg};
+use cw_storage_plus::Bound;
 use crate::error::ContractError;
+use crate::msg::{
+    TimelockTransactionResponse, TimelockTransactionSummary, TimelockTransactionsResponse,
+};
 use crate::state::{
-    Config, MultisigConfig, TimelockTransaction, AccessControlRole,
-    CONFIG, TIMELOCK_TRANSACTIONS, ACCESS_CONTROL_ROLES, ROLE_MEMBERS
+    Config, MultisigConfig, TimelockTransaction, TimelockStatus, AccessControlRole,
+    CONFIG, TIMELOCK_TRANSACTIONS, ACCESS_CONTROL_ROLES, ROLE_MEMBERS, ROLE_MEMBER_COUNTS
 };
 
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 100;
+
 // Role-based access control constants
 pub const ADMIN_ROLE: &str = "ADMIN";
 pub const CIRCUIT_MANAGER_ROLE: &str = "CIRCUIT_MANAGER";
 pub const ISSUER_MANAGER_ROLE: &str = "ISSUER_MANAGER";
 pub const GOVERNANCE_ROLE: &str = "GOVERNANCE";
+/// May call `ScheduleTimelockTransaction`, subject to `min_timelock_delay`.
+pub const PROPOSER_ROLE: &str = "PROPOSER";
+/// May call `ExecuteTimelockTransaction` once a transaction is ripe, unless
+/// the transaction's own `executors` allowlist is empty, in which case any
+/// address may execute it (see `execute_timelock_transaction`).
+pub const EXECUTOR_ROLE: &str = "EXECUTOR";
 
 /// Check if an address has a specific role
 pub fn has_role(deps: Deps, role: &str, account: &Addr) -> StdResult<bool> {
@@ -28,6 +41,22 @@ pub fn require_role(deps: Deps, role: &str, sender: &Addr) -> Result<(), Contrac
     Ok(())
 }
 
+/// Reject any access-control reconfiguration once `Config::frozen` is set
+/// via `ExecuteMsg::FreezeTimelock`: role grants/revokes and re-pointing a
+/// role's admin go through this, and `contract::execute_update_admin`
+/// checks the same `Config::frozen` flag inline. The freeze has
+/// no unfreeze path, so this is the contract's permanent guarantee that
+/// its governance surface can no longer change; scheduling/executing
+/// already-queued timelock transactions is untouched (see
+/// `schedule_timelock_transaction`/`execute_timelock_transaction`, which
+/// don't call this).
+fn require_not_frozen(deps: Deps) -> Result<(), ContractError> {
+    if CONFIG.load(deps.storage)?.frozen {
+        return Err(ContractError::TimelockFrozen {});
+    }
+    Ok(())
+}
+
 /// Grant role to an account (only role admin can do this)
 pub fn grant_role(
     deps: DepsMut,
@@ -35,6 +64,8 @@ pub fn grant_role(
     role: &str,
     account: &Addr,
 ) -> Result<Response, ContractError> {
+    require_not_frozen(deps.as_ref())?;
+
     // Check if sender can manage this role
     let role_info = ACCESS_CONTROL_ROLES.may_load(deps.storage, role)?;
     
@@ -64,11 +95,15 @@ pub fn grant_role(
     // Grant the role
     ROLE_MEMBERS.save(deps.storage, (role, account.as_str()), &true)?;
 
-    // Update role members list
+    // Update role members list and the member counter, only for accounts
+    // that weren't already members (re-granting is a no-op for both).
     let mut role_data = ACCESS_CONTROL_ROLES.load(deps.storage, role)?;
     if !role_data.members.contains(account) {
         role_data.members.push(account.clone());
         ACCESS_CONTROL_ROLES.save(deps.storage, role, &role_data)?;
+
+        let count = ROLE_MEMBER_COUNTS.may_load(deps.storage, role)?.unwrap_or(0);
+        ROLE_MEMBER_COUNTS.save(deps.storage, role, &(count + 1))?;
     }
 
     Ok(Response::new()
@@ -85,6 +120,8 @@ pub fn revoke_role(
     role: &str,
     account: &Addr,
 ) -> Result<Response, ContractError> {
+    require_not_frozen(deps.as_ref())?;
+
     let role_info = ACCESS_CONTROL_ROLES.load(deps.storage, role)?;
     
     if let Some(admin_role) = &role_info.admin_role {
@@ -96,8 +133,13 @@ pub fn revoke_role(
     // Revoke the role
     ROLE_MEMBERS.remove(deps.storage, (role, account.as_str()));
 
-    // Update role members list
+    // Update role members list and the member counter, only decrementing
+    // for an account that was actually present.
     let mut role_data = role_info;
+    if role_data.members.contains(account) {
+        let count = ROLE_MEMBER_COUNTS.may_load(deps.storage, role)?.unwrap_or(0);
+        ROLE_MEMBER_COUNTS.save(deps.storage, role, &count.saturating_sub(1))?;
+    }
     role_data.members.retain(|addr| addr != account);
     ACCESS_CONTROL_ROLES.save(deps.storage, role, &role_data)?;
 
@@ -113,12 +155,13 @@ pub fn schedule_timelock_transaction(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    target_function: String,
-    params: String,
+    msgs: Vec<CosmosMsg>,
     delay: u64,
+    executors: Option<Vec<String>>,
+    grace_period: Option<u64>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    
+
     if !config.timelock_enabled {
         return Err(ContractError::TimelockNotEnabled {});
     }
@@ -130,22 +173,35 @@ pub fn schedule_timelock_transaction(
         });
     }
 
-    // Only admin or authorized roles can schedule timelock transactions
-    require_role(deps.as_ref(), ADMIN_ROLE, &info.sender)?;
+    // Scheduling is the PROPOSER_ROLE's responsibility; who may execute it
+    // later is checked separately in `execute_timelock_transaction`.
+    require_role(deps.as_ref(), PROPOSER_ROLE, &info.sender)?;
+
+    let executors: Vec<Addr> = executors
+        .unwrap_or_default()
+        .iter()
+        .map(|a| deps.api.addr_validate(a))
+        .collect::<StdResult<Vec<_>>>()?;
 
     let transaction_id = get_next_timelock_id(deps.storage)?;
     let scheduled_time = env.block.time.seconds() + delay;
 
+    // Fall back to the contract-wide default when the caller didn't pin a
+    // grace period explicitly (see `Config::default_timelock_grace_period`).
+    let grace_period = grace_period.or(config.default_timelock_grace_period);
+
     let timelock_tx = TimelockTransaction {
         id: transaction_id,
         proposer: info.sender,
-        target_function,
-        params,
+        msgs,
         scheduled_time,
         executed: false,
         cancelled: false,
         approvals: vec![],
         created_at: env.block.time.seconds(),
+        executors,
+        grace_period,
+        status: TimelockStatus::Pending,
     };
 
     TIMELOCK_TRANSACTIONS.save(deps.storage, transaction_id, &timelock_tx)?;
@@ -156,6 +212,51 @@ pub fn schedule_timelock_transaction(
         .add_attribute("scheduled_time", scheduled_time.to_string()))
 }
 
+/// Schedule a timelock transaction on behalf of a passed governance
+/// proposal. Unlike `schedule_timelock_transaction`, this skips the
+/// `PROPOSER_ROLE` check and `min_timelock_delay` bound validation — the
+/// DAO vote itself is the authorization. The delay is the larger of
+/// `Config::min_timelock_delay` and the proposal's `requested_delay`, so a
+/// requested delay can lengthen it but never shorten it.
+pub fn schedule_governance_timelock_transaction(
+    deps: DepsMut,
+    env: &Env,
+    proposer: Addr,
+    msgs: Vec<CosmosMsg>,
+    requested_delay: Option<u64>,
+) -> Result<(u64, u64), ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if !config.timelock_enabled {
+        return Err(ContractError::TimelockNotEnabled {});
+    }
+
+    let transaction_id = get_next_timelock_id(deps.storage)?;
+    // A proposer can ask for a longer delay than the contract-wide minimum
+    // (e.g. to give a particularly sensitive change more review time), but
+    // never a shorter one.
+    let delay = config.min_timelock_delay.max(requested_delay.unwrap_or(0));
+    let scheduled_time = env.block.time.seconds() + delay;
+
+    let timelock_tx = TimelockTransaction {
+        id: transaction_id,
+        proposer,
+        msgs,
+        scheduled_time,
+        executed: false,
+        cancelled: false,
+        approvals: vec![],
+        created_at: env.block.time.seconds(),
+        executors: vec![],
+        grace_period: config.default_timelock_grace_period,
+        status: TimelockStatus::Pending,
+    };
+
+    TIMELOCK_TRANSACTIONS.save(deps.storage, transaction_id, &timelock_tx)?;
+
+    Ok((transaction_id, scheduled_time))
+}
+
 /// Execute a timelock transaction
 pub fn execute_timelock_transaction(
     deps: DepsMut,
@@ -180,6 +281,28 @@ pub fn execute_timelock_transaction(
         });
     }
 
+    if let Some(grace_period) = timelock_tx.grace_period {
+        let expires_at = timelock_tx.scheduled_time + grace_period;
+        if env.block.time.seconds() > expires_at {
+            return Err(ContractError::TimelockExpired {
+                id: transaction_id,
+                expired_at: expires_at,
+            });
+        }
+    }
+
+    // An empty per-tx allowlist means open execution once ripe; otherwise
+    // the sender must be in that allowlist or hold EXECUTOR_ROLE.
+    if !timelock_tx.executors.is_empty()
+        && !timelock_tx.executors.contains(&info.sender)
+        && !has_role(deps.as_ref(), EXECUTOR_ROLE, &info.sender)?
+    {
+        return Err(ContractError::MissingRole {
+            role: EXECUTOR_ROLE.to_string(),
+            account: info.sender.to_string(),
+        });
+    }
+
     let config = CONFIG.load(deps.storage)?;
 
     // Check multisig requirements if enabled
@@ -206,14 +329,61 @@ pub fn execute_timelock_transaction(
 
     // Mark as executed
     timelock_tx.executed = true;
+    timelock_tx.status = TimelockStatus::Executed;
     TIMELOCK_TRANSACTIONS.save(deps.storage, transaction_id, &timelock_tx)?;
 
     Ok(Response::new()
+        .add_messages(timelock_tx.msgs)
         .add_attribute("action", "execute_timelock")
         .add_attribute("transaction_id", transaction_id.to_string())
         .add_attribute("executor", info.sender))
 }
 
+/// On-chain cron queue: drain every `TIMELOCK_TRANSACTIONS` entry that's
+/// currently `TimelockStatus::Ready` (scheduled_time passed, not expired)
+/// up to `limit` (default 10), so scheduled transactions don't depend on
+/// an external caller knowing each id ahead of time — anyone can crank the
+/// queue. Reuses `execute_timelock_transaction`'s own authorization and
+/// multisig checks per transaction rather than bypassing them: a ready
+/// transaction the cranker isn't allowed to execute (per-tx allowlist,
+/// `EXECUTOR_ROLE`, multisig threshold) is skipped, not force-executed, so
+/// cranking never grants permissions execution itself wouldn't.
+pub fn crank_timelock_queue(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let limit = limit.unwrap_or(10).max(1) as usize;
+    let now = env.block.time.seconds();
+
+    let ready_ids: Vec<u64> = TIMELOCK_TRANSACTIONS
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter_map(|entry| entry.ok())
+        .filter(|(_, tx)| effective_timelock_status(tx, now) == TimelockStatus::Ready)
+        .map(|(id, _)| id)
+        .take(limit)
+        .collect();
+
+    let mut response = Response::new().add_attribute("action", "crank_timelock_queue");
+    let mut executed_ids = Vec::new();
+    let mut skipped_ids = Vec::new();
+
+    for id in ready_ids {
+        match execute_timelock_transaction(deps.branch(), env.clone(), info.clone(), id) {
+            Ok(sub_response) => {
+                response = response.add_messages(sub_response.messages.into_iter().map(|m| m.msg));
+                executed_ids.push(id.to_string());
+            }
+            Err(_) => skipped_ids.push(id.to_string()),
+        }
+    }
+
+    Ok(response
+        .add_attribute("executed", executed_ids.join(","))
+        .add_attribute("skipped", skipped_ids.join(",")))
+}
+
 /// Approve a timelock transaction (for multisig)
 pub fn approve_timelock_transaction(
     deps: DepsMut,
@@ -255,6 +425,9 @@ pub fn approve_timelock_transaction(
 
     // Add approval
     timelock_tx.approvals.push(info.sender.clone());
+    if timelock_tx.approvals.len() >= multisig.threshold as usize {
+        timelock_tx.status = TimelockStatus::Approved;
+    }
     TIMELOCK_TRANSACTIONS.save(deps.storage, transaction_id, &timelock_tx)?;
 
     Ok(Response::new()
@@ -264,6 +437,77 @@ pub fn approve_timelock_transaction(
         .add_attribute("total_approvals", timelock_tx.approvals.len().to_string()))
 }
 
+/// Cancel a scheduled timelock transaction. Callable by `ADMIN_ROLE`, the
+/// original proposer, or a `Config::multisig_config` signer (the same set
+/// of principals `approve_timelock_transaction` already trusts), as long as
+/// it hasn't already executed.
+pub fn cancel_timelock_transaction(
+    deps: DepsMut,
+    info: MessageInfo,
+    transaction_id: u64,
+) -> Result<Response, ContractError> {
+    let mut timelock_tx = TIMELOCK_TRANSACTIONS.load(deps.storage, transaction_id)?;
+
+    if timelock_tx.executed {
+        return Err(ContractError::TimelockAlreadyExecuted { id: transaction_id });
+    }
+
+    if timelock_tx.cancelled {
+        return Err(ContractError::TimelockCancelled { id: transaction_id });
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    let is_multisig_signer = config.multisig_config
+        .as_ref()
+        .is_some_and(|multisig| multisig.signers.contains(&info.sender));
+
+    if info.sender != timelock_tx.proposer
+        && !is_multisig_signer
+        && !has_role(deps.as_ref(), ADMIN_ROLE, &info.sender)?
+    {
+        return Err(ContractError::MissingRole {
+            role: ADMIN_ROLE.to_string(),
+            account: info.sender.to_string(),
+        });
+    }
+
+    timelock_tx.cancelled = true;
+    timelock_tx.status = TimelockStatus::Cancelled;
+    TIMELOCK_TRANSACTIONS.save(deps.storage, transaction_id, &timelock_tx)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "cancel_timelock")
+        .add_attribute("transaction_id", transaction_id.to_string())
+        .add_attribute("cancelled_by", info.sender))
+}
+
+/// Re-point a role's admin role. Callable only by the role's *current*
+/// admin (defaulting to `ADMIN_ROLE` for roles with no `admin_role` set),
+/// mirroring the authorization check in `grant_role`/`revoke_role`.
+pub fn set_role_admin(
+    deps: DepsMut,
+    info: MessageInfo,
+    role: &str,
+    admin_role: &str,
+) -> Result<Response, ContractError> {
+    require_not_frozen(deps.as_ref())?;
+
+    let mut role_data = ACCESS_CONTROL_ROLES.load(deps.storage, role)
+        .map_err(|_| ContractError::RoleNotFound { role: role.to_string() })?;
+
+    let current_admin_role = role_data.admin_role.as_deref().unwrap_or(ADMIN_ROLE);
+    require_role(deps.as_ref(), current_admin_role, &info.sender)?;
+
+    role_data.admin_role = Some(admin_role.to_string());
+    ACCESS_CONTROL_ROLES.save(deps.storage, role, &role_data)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_role_admin")
+        .add_attribute("role", role)
+        .add_attribute("admin_role", admin_role)
+        .add_attribute("set_by", info.sender))
+}
+
 /// Initialize default roles
 pub fn initialize_roles(deps: DepsMut, admin: &Addr) -> StdResult<()> {
     // Create admin role
@@ -274,6 +518,7 @@ pub fn initialize_roles(deps: DepsMut, admin: &Addr) -> StdResult<()> {
     };
     ACCESS_CONTROL_ROLES.save(deps.storage, ADMIN_ROLE, &admin_role)?;
     ROLE_MEMBERS.save(deps.storage, (ADMIN_ROLE, admin.as_str()), &true)?;
+    ROLE_MEMBER_COUNTS.save(deps.storage, ADMIN_ROLE, &1)?;
 
     // Create circuit manager role
     let circuit_manager_role = AccessControlRole {
@@ -299,6 +544,44 @@ pub fn initialize_roles(deps: DepsMut, admin: &Addr) -> StdResult<()> {
     };
     ACCESS_CONTROL_ROLES.save(deps.storage, GOVERNANCE_ROLE, &governance_role)?;
 
+    // Create proposer role; admin is seeded as a member so it can schedule
+    // timelock transactions out of the box.
+    let proposer_role = AccessControlRole {
+        role_name: PROPOSER_ROLE.to_string(),
+        members: vec![admin.clone()],
+        admin_role: Some(ADMIN_ROLE.to_string()),
+    };
+    ACCESS_CONTROL_ROLES.save(deps.storage, PROPOSER_ROLE, &proposer_role)?;
+    ROLE_MEMBERS.save(deps.storage, (PROPOSER_ROLE, admin.as_str()), &true)?;
+    ROLE_MEMBER_COUNTS.save(deps.storage, PROPOSER_ROLE, &1)?;
+
+    // Create executor role; additional members come from
+    // `InstantiateMsg::executor_allowlist` via `seed_role_member`.
+    let executor_role = AccessControlRole {
+        role_name: EXECUTOR_ROLE.to_string(),
+        members: vec![admin.clone()],
+        admin_role: Some(ADMIN_ROLE.to_string()),
+    };
+    ACCESS_CONTROL_ROLES.save(deps.storage, EXECUTOR_ROLE, &executor_role)?;
+    ROLE_MEMBERS.save(deps.storage, (EXECUTOR_ROLE, admin.as_str()), &true)?;
+    ROLE_MEMBER_COUNTS.save(deps.storage, EXECUTOR_ROLE, &1)?;
+
+    Ok(())
+}
+
+/// Grant `role` to `account` with no authorization check, for wiring up
+/// `InstantiateMsg`-supplied initial role members (e.g. `executor_allowlist`)
+/// before any caller could plausibly hold a role to check against.
+pub fn seed_role_member(deps: DepsMut, role: &str, account: &Addr) -> StdResult<()> {
+    ROLE_MEMBERS.save(deps.storage, (role, account.as_str()), &true)?;
+    let mut role_data = ACCESS_CONTROL_ROLES.load(deps.storage, role)?;
+    if !role_data.members.contains(account) {
+        role_data.members.push(account.clone());
+        ACCESS_CONTROL_ROLES.save(deps.storage, role, &role_data)?;
+
+        let count = ROLE_MEMBER_COUNTS.may_load(deps.storage, role)?.unwrap_or(0);
+        ROLE_MEMBER_COUNTS.save(deps.storage, role, &(count + 1))?;
+    }
     Ok(())
 }
 
@@ -315,22 +598,152 @@ fn get_next_timelock_id(storage: &dyn cosmwasm_std::Storage) -> StdResult<u64> {
     Ok(max_id + 1)
 }
 
-/// Query role members
-pub fn query_role_members(deps: Deps, role: &str) -> StdResult<Vec<Addr>> {
-    let members: Result<Vec<_>, _> = ROLE_MEMBERS
+/// Query role members, paginated by member address.
+pub fn query_role_members(
+    deps: Deps,
+    role: &str,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<Addr>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.as_deref().map(Bound::exclusive);
+
+    ROLE_MEMBERS
         .prefix(role)
-        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
         .map(|item| {
             let (addr_str, _) = item?;
             Ok(Addr::unchecked(addr_str))
         })
-        .collect();
-    
-    members
+        .collect()
+}
+
+/// Every member of `role`, unpaginated. Unlike `query_role_members`, this
+/// isn't exposed to callers directly; it backs operations like
+/// `contract::execute_claim_rewards` that must see every member rather
+/// than a capped page of them.
+pub fn all_role_members(deps: Deps, role: &str) -> StdResult<Vec<Addr>> {
+    ROLE_MEMBERS
+        .prefix(role)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (addr_str, _) = item?;
+            Ok(Addr::unchecked(addr_str))
+        })
+        .collect()
+}
+
+/// Number of members currently holding `role`.
+pub fn query_role_member_count(deps: Deps, role: &str) -> StdResult<u64> {
+    Ok(ROLE_MEMBER_COUNTS.may_load(deps.storage, role)?.unwrap_or(0))
+}
+
+/// Every role with at least one member.
+pub fn query_list_roles(deps: Deps) -> StdResult<Vec<String>> {
+    ACCESS_CONTROL_ROLES
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter_map(|item| match item {
+            Ok((role, role_data)) if !role_data.members.is_empty() => Some(Ok(role)),
+            Ok(_) => None,
+            Err(e) => Some(Err(e)),
+        })
+        .collect()
+}
+
+/// The role currently allowed to grant/revoke `role` (defaults to
+/// `ADMIN_ROLE` when `role` has no `admin_role` set, same fallback as
+/// `grant_role`/`revoke_role`).
+pub fn query_role_admin(deps: Deps, role: &str) -> Result<String, ContractError> {
+    let role_data = ACCESS_CONTROL_ROLES.load(deps.storage, role)
+        .map_err(|_| ContractError::RoleNotFound { role: role.to_string() })?;
+    Ok(role_data.admin_role.unwrap_or_else(|| ADMIN_ROLE.to_string()))
+}
+
+/// Derive a transaction's effective lifecycle status at query time.
+/// `executed`/`cancelled` are terminal and always win; otherwise a past
+/// `scheduled_time + grace_period` reports `Expired` and a past
+/// `scheduled_time` (with no expiry yet) reports `Ready`, so callers don't
+/// have to recompute this arithmetic client-side. Anything else falls back
+/// to the stored status (`Pending`, or `Approved` once multisig threshold
+/// is met but the delay hasn't elapsed).
+fn effective_timelock_status(tx: &TimelockTransaction, now: u64) -> TimelockStatus {
+    if tx.executed {
+        return TimelockStatus::Executed;
+    }
+    if tx.cancelled {
+        return TimelockStatus::Cancelled;
+    }
+    if let Some(grace_period) = tx.grace_period {
+        if now > tx.scheduled_time + grace_period {
+            return TimelockStatus::Expired;
+        }
+    }
+    if now >= tx.scheduled_time {
+        return TimelockStatus::Ready;
+    }
+    tx.status.clone()
 }
 
 /// Query timelock transaction
-pub fn query_timelock_transaction(deps: Deps, transaction_id: u64) -> Result<TimelockTransaction, ContractError> {
-    TIMELOCK_TRANSACTIONS.load(deps.storage, transaction_id)
-        .map_err(|_| ContractError::TimelockNotFound { id: transaction_id })
+pub fn query_timelock_transaction(
+    deps: Deps,
+    env: &Env,
+    transaction_id: u64,
+) -> Result<TimelockTransactionResponse, ContractError> {
+    let tx = TIMELOCK_TRANSACTIONS.load(deps.storage, transaction_id)
+        .map_err(|_| ContractError::TimelockNotFound { id: transaction_id })?;
+    let status = effective_timelock_status(&tx, env.block.time.seconds());
+
+    Ok(TimelockTransactionResponse {
+        id: tx.id,
+        proposer: tx.proposer,
+        msgs: tx.msgs,
+        scheduled_time: tx.scheduled_time,
+        executed: tx.executed,
+        cancelled: tx.cancelled,
+        approvals: tx.approvals,
+        created_at: tx.created_at,
+        executors: tx.executors,
+        grace_period: tx.grace_period,
+        status,
+    })
+}
+
+/// Page through `TIMELOCK_TRANSACTIONS` oldest-id-first, summarizing each
+/// entry's effective status the same way `query_timelock_transaction` does,
+/// so operators can review the full pending queue without loading every
+/// transaction's full message payload.
+pub fn query_list_timelock_transactions(
+    deps: Deps,
+    env: &Env,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+    status: Option<TimelockStatus>,
+) -> StdResult<TimelockTransactionsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+    let now = env.block.time.seconds();
+
+    let transactions: StdResult<Vec<_>> = TIMELOCK_TRANSACTIONS
+        .range(deps.storage, start, None, Order::Ascending)
+        .filter(|item| match item {
+            Ok((_, tx)) => status
+                .as_ref()
+                .map_or(true, |status| &effective_timelock_status(tx, now) == status),
+            Err(_) => true,
+        })
+        .take(limit)
+        .map(|item| {
+            let (id, tx) = item?;
+            Ok(TimelockTransactionSummary {
+                id,
+                status: effective_timelock_status(&tx, now),
+                scheduled_time: tx.scheduled_time,
+                approvals_count: tx.approvals.len() as u64,
+            })
+        })
+        .collect();
+
+    Ok(TimelockTransactionsResponse { transactions: transactions? })
 }
\ No newline at end of file
@@ -1,634 +0,0 @@
-use cosmwasm_std::{
-    entry_point, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
-    Order, StdError, Addr, CosmosMsg, WasmMsg, to_json_vec, BankMsg, Coin,
-};
-use cw_storage_plus::Bound;
-use cw2::set_contract_version;
-use sha2::{Digest, Sha256};
-use std::collections::HashMap;
-
-use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, CircuitResponse, CircuitsResponse, 
-    ProofResponse, ProofsResponse, ContractInfoResponse, IssuerResponse, IssuersResponse,
-    ProposalResponse, ProposalsResponse, BatchOperationResponse, AuditLogResponse,
-    ComplianceReportResponse, MerkleProofResponse, CrossChainTxResponse, CircuitRegistration,
-    ProofSubmission};
-use crate::state::{Config, Circuit, Proof, Issuer, GovernanceProposal, ProposalType,
-    CONFIG, CIRCUITS, PROOFS, CIRCUIT_PROOFS, ISSUERS, GOVERNANCE_PROPOSALS, VOTERS,
-    BATCH_OPERATIONS, AUDIT_LOG, COMPLIANCE_REPORTS, MERKLE_TREES, CROSS_CHAIN_TXS,
-    BatchOperation, AuditLogEntry, ComplianceReport, MerkleTree, CrossChainTransaction,
-    DELEGATIONS, EMERGENCY_CONTACTS, RATE_LIMITS, PERFORMANCE_METRICS};
-use crate::verifier::{verify_proof, validate_verification_key, validate_proof, 
-    verify_merkle_proof, batch_verify_proofs};
-
-// version info for migration
-const CONTRACT_NAME: &str = "crates.io:zk-verifier-enterprise";
-const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
-
-const DEFAULT_LIMIT: u32 = 10;
-const MAX_LIMIT: u32 = 100;
-const MAX_BATCH_SIZE: u32 = 1000;
-const RATE_LIMIT_WINDOW: u64 = 3600; // 1 hour in seconds
-
-#[entry_point]
-pub fn instantiate(
-    deps: DepsMut,
-    env: Env,
-    info: MessageInfo,
-    msg: InstantiateMsg,
-) -> Result<Response, ContractError> {
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-
-    let admin = msg.admin
-        .map(|a| deps.api.addr_validate(&a))
-        .transpose()?
-        .unwrap_or(info.sender);
-
-    let config = Config {
-        admin: admin.clone(),
-        total_circuits: 0,
-        total_proofs: 0,
-        governance_enabled: msg.governance_enabled.unwrap_or(false),
-        dao_address: msg.dao_address
-            .map(|a| deps.api.addr_validate(&a))
-            .transpose()?,
-        multisig_config: msg.multisig_config,
-        timelock_enabled: msg.timelock_enabled.unwrap_or(false),
-        min_timelock_delay: msg.min_timelock_delay.unwrap_or(3600),
-        // Enterprise features
-        compliance_enabled: msg.compliance_enabled.unwrap_or(true),
-        audit_enabled: msg.audit_enabled.unwrap_or(true),
-        cross_chain_enabled: msg.cross_chain_enabled.unwrap_or(false),
-        performance_monitoring: msg.performance_monitoring.unwrap_or(true),
-        rate_limiting_enabled: msg.rate_limiting_enabled.unwrap_or(true),
-        emergency_pause_enabled: msg.emergency_pause_enabled.unwrap_or(false),
-        max_batch_size: msg.max_batch_size.unwrap_or(MAX_BATCH_SIZE),
-        supported_chains: msg.supported_chains.unwrap_or_default(),
-        ibc_enabled: msg.ibc_enabled.unwrap_or(false),
-        layer2_enabled: msg.layer2_enabled.unwrap_or(false),
-        state_channel_enabled: msg.state_channel_enabled.unwrap_or(false),
-    };
-    CONFIG.save(deps.storage, &config)?;
-
-    // Initialize audit log
-    let initial_audit = AuditLogEntry {
-        id: 0,
-        event_type: "contract_instantiation".to_string(),
-        actor: info.sender.to_string(),
-        target: "contract".to_string(),
-        timestamp: env.block.time.seconds(),
-        details: format!("Contract instantiated by {}", info.sender),
-        block_height: env.block.height,
-        tx_hash: "".to_string(),
-        risk_score: 0,
-        compliance_flags: vec![],
-    };
-    AUDIT_LOG.save(deps.storage, 0, &initial_audit)?;
-
-    Ok(Response::new()
-        .add_attribute("method", "instantiate")
-        .add_attribute("admin", config.admin)
-        .add_attribute("contract_version", CONTRACT_VERSION)
-        .add_attribute("enterprise_features", "enabled"))
-}
-
-#[entry_point]
-pub fn execute(
-    deps: DepsMut,
-    env: Env,
-    info: MessageInfo,
-    msg: ExecuteMsg,
-) -> Result<Response, ContractError> {
-    // Rate limiting check
-    let config = CONFIG.load(deps.storage)?;
-    if config.rate_limiting_enabled {
-        check_rate_limit(deps.storage, &info.sender, &env)?;
-    }
-
-    // Emergency pause check
-    if config.emergency_pause_enabled {
-        return Err(ContractError::EmergencyPause {});
-    }
-
-    // Audit logging
-    if config.audit_enabled {
-        let audit_entry = AuditLogEntry {
-            id: get_next_audit_id(deps.storage)?,
-            event_type: get_message_type(&msg),
-            actor: info.sender.to_string(),
-            target: get_message_target(&msg),
-            timestamp: env.block.time.seconds(),
-            details: format!("Execute: {}", get_message_type(&msg)),
-            block_height: env.block.height,
-            tx_hash: "".to_string(),
-            risk_score: calculate_risk_score(&msg),
-            compliance_flags: vec![],
-        };
-        AUDIT_LOG.save(deps.storage, audit_entry.id, &audit_entry)?;
-    }
-
-    match msg {
-        ExecuteMsg::RegisterCircuit {
-            circuit_id,
-            verification_key,
-            circuit_type,
-        } => execute_register_circuit(deps, env, info, circuit_id, verification_key, circuit_type),
-        ExecuteMsg::RegisterCircuitBatch {
-            circuits,
-        } => execute_register_circuit_batch(deps, env, info, circuits),
-        ExecuteMsg::DeactivateCircuit { circuit_id } => {
-            execute_deactivate_circuit(deps, env, info, circuit_id)
-        }
-        ExecuteMsg::SubmitProof {
-            circuit_id,
-            public_inputs,
-            proof,
-        } => execute_submit_proof(deps, env, info, circuit_id, public_inputs, proof),
-        ExecuteMsg::SubmitProofBatch {
-            proofs,
-        } => execute_submit_proof_batch(deps, env, info, proofs),
-        ExecuteMsg::UpdateAdmin { new_admin } => {
-            execute_update_admin(deps, env, info, new_admin)
-        }
-        ExecuteMsg::AddIssuer { issuer_address, authorized_circuits } => {
-            execute_add_issuer(deps, env, info, issuer_address, authorized_circuits)
-        }
-        ExecuteMsg::RemoveIssuer { issuer_address } => {
-            execute_remove_issuer(deps, env, info, issuer_address)
-        }
-        ExecuteMsg::SubmitGovernanceProposal { title, description, proposal_type } => {
-            execute_submit_governance_proposal(deps, env, info, title, description, proposal_type)
-        }
-        ExecuteMsg::VoteOnProposal { proposal_id, vote } => {
-            execute_vote_on_proposal(deps, env, info, proposal_id, vote)
-        }
-        ExecuteMsg::ExecuteProposal { proposal_id } => {
-            execute_governance_proposal(deps, env, info, proposal_id)
-        }
-        ExecuteMsg::GrantRole { role, account } => {
-            let validated_account = deps.api.addr_validate(&account)?;
-            crate::access_control::grant_role(deps, info, &role, &validated_account)
-        }
-        ExecuteMsg::RevokeRole { role, account } => {
-            let validated_account = deps.api.addr_validate(&account)?;
-            crate::access_control::revoke_role(deps, info, &role, &validated_account)
-        }
-        ExecuteMsg::ScheduleTimelockTransaction { target_function, params, delay } => {
-            crate::access_control::schedule_timelock_transaction(deps, env, info, target_function, params, delay)
-        }
-        ExecuteMsg::ExecuteTimelockTransaction { transaction_id } => {
-            crate::access_control::execute_timelock_transaction(deps, env, info, transaction_id)
-        }
-        ExecuteMsg::ApproveTimelockTransaction { transaction_id } => {
-            crate::access_control::approve_timelock_transaction(deps, info, transaction_id)
-        }
-        ExecuteMsg::EmergencyPause {} => {
-            execute_emergency_pause(deps, env, info)
-        }
-        ExecuteMsg::EmergencyUnpause {} => {
-            execute_emergency_unpause(deps, env, info)
-        }
-        ExecuteMsg::SubmitCrossChainTransaction {
-            target_chain,
-            target_contract,
-            payload,
-        } => execute_submit_cross_chain_transaction(deps, env, info, target_chain, target_contract, payload),
-        ExecuteMsg::ProcessCrossChainResponse {
-            tx_id,
-            response,
-        } => execute_process_cross_chain_response(deps, env, info, tx_id, response),
-        ExecuteMsg::CreateMerkleTree {
-            tree_id,
-            leaves,
-        } => execute_create_merkle_tree(deps, env, info, tree_id, leaves),
-        ExecuteMsg::AddDelegate {
-            circuit_id,
-            delegate,
-            permissions,
-        } => execute_add_delegate(deps, env, info, circuit_id, delegate, permissions),
-        ExecuteMsg::RemoveDelegate {
-            circuit_id,
-            delegate,
-        } => execute_remove_delegate(deps, env, info, circuit_id, delegate),
-        ExecuteMsg::SetEmergencyContact {
-            contact_type,
-            contact_address,
-        } => execute_set_emergency_contact(deps, env, info, contact_type, contact_address),
-        ExecuteMsg::GenerateComplianceReport {
-            start_time,
-            end_time,
-            report_type,
-        } => execute_generate_compliance_report(deps, env, info, start_time, end_time, report_type),
-    }
-}
-
-// Enhanced circuit registration with enterprise features
-pub fn execute_register_circuit(
-    deps: DepsMut,
-    env: Env,
-    info: MessageInfo,
-    circuit_id: String,
-    verification_key: String,
-    circuit_type: String,
-) -> Result<Response, ContractError> {
-    let mut config = CONFIG.load(deps.storage)?;
-
-    // Enhanced authorization checks
-    crate::access_control::require_role(deps.as_ref(), "CIRCUIT_ADMIN", &info.sender)?;
-
-    // Validate inputs with enhanced security
-    if circuit_id.is_empty() || circuit_id.len() > 64 {
-        return Err(ContractError::InvalidCircuitId { circuit_id });
-    }
-
-    // Advanced verification key validation
-    validate_verification_key(&verification_key)
-        .map_err(|e| ContractError::InvalidVerificationKey { error: e.to_string() })?;
-
-    // Check if circuit already exists
-    if CIRCUITS.has(deps.storage, &circuit_id) {
-        return Err(ContractError::CircuitAlreadyExists { circuit_id });
-    }
-
-    // Validate circuit type against supported types
-    let supported_types = vec!["groth16", "plonk", "stark", "bulletproofs"];
-    if !supported_types.contains(&circuit_type.as_str()) {
-        return Err(ContractError::UnsupportedCircuitType { circuit_type });
-    }
-
-    let circuit = Circuit {
-        circuit_id: circuit_id.clone(),
-        verification_key: verification_key.clone(),
-        circuit_type: circuit_type.clone(),
-        creator: info.sender.clone(),
-        active: true,
-        created_at: env.block.time.seconds(),
-        // Enhanced fields
-        version: 1,
-        metadata_hash: Sha256::digest(verification_key.as_bytes()).to_vec(),
-        gas_estimate: calculate_gas_estimate(&circuit_type),
-        security_level: get_security_level(&circuit_type),
-        supported_features: get_supported_features(&circuit_type),
-        max_public_inputs: get_max_public_inputs(&circuit_type),
-        performance_metrics: HashMap::new(),
-    };
-
-    CIRCUITS.save(deps.storage, &circuit_id, &circuit)?;
-    
-    config.total_circuits += 1;
-    CONFIG.save(deps.storage, &config)?;
-
-    Ok(Response::new()
-        .add_attribute("method", "register_circuit")
-        .add_attribute("circuit_id", circuit_id)
-        .add_attribute("circuit_type", circuit_type)
-        .add_attribute("creator", circuit.creator)
-        .add_attribute("security_level", circuit.security_level.to_string()))
-}
-
-// Batch circuit registration for enterprise efficiency
-pub fn execute_register_circuit_batch(
-    deps: DepsMut,
-    env: Env,
-    info: MessageInfo,
-    circuits: Vec<CircuitRegistration>,
-) -> Result<Response, ContractError> {
-    let config = CONFIG.load(deps.storage)?;
-    
-    if circuits.len() > config.max_batch_size as usize {
-        return Err(ContractError::BatchSizeExceeded { 
-            max_size: config.max_batch_size,
-            provided: circuits.len() as u32,
-        });
-    }
-
-    crate::access_control::require_role(deps.as_ref(), "CIRCUIT_ADMIN", &info.sender)?;
-
-    let mut registered_circuits = Vec::new();
-    let mut total_gas = 0u64;
-
-    for circuit_reg in circuits {
-        // Validate each circuit
-        validate_verification_key(&circuit_reg.verification_key)?;
-        
-        if CIRCUITS.has(deps.storage, &circuit_reg.circuit_id) {
-            return Err(ContractError::CircuitAlreadyExists { 
-                circuit_id: circuit_reg.circuit_id 
-            });
-        }
-
-        let circuit = Circuit {
-            circuit_id: circuit_reg.circuit_id.clone(),
-            verification_key: circuit_reg.verification_key.clone(),
-            circuit_type: circuit_reg.circuit_type.clone(),
-            creator: info.sender.clone(),
-            active: true,
-            created_at: env.block.time.seconds(),
-            version: 1,
-            metadata_hash: Sha256::digest(circuit_reg.verification_key.as_bytes()).to_vec(),
-            gas_estimate: calculate_gas_estimate(&circuit_reg.circuit_type),
-            security_level: get_security_level(&circuit_reg.circuit_type),
-            supported_features: get_supported_features(&circuit_reg.circuit_type),
-            max_public_inputs: get_max_public_inputs(&circuit_reg.circuit_type),
-            performance_metrics: HashMap::new(),
-        };
-
-        total_gas += circuit.gas_estimate;
-        CIRCUITS.save(deps.storage, &circuit_reg.circuit_id, &circuit)?;
-        registered_circuits.push(circuit_reg.circuit_id.clone());
-    }
-
-    // Create batch operation record
-    let batch_op = BatchOperation {
-        id: get_next_batch_id(deps.storage)?,
-        operation_type: "circuit_registration".to_string(),
-        items: registered_circuits.clone(),
-        executor: info.sender.clone(),
-        timestamp: env.block.time.seconds(),
-        status: "completed".to_string(),
-        gas_used: total_gas,
-        result_hash: Sha256::digest(format!("{:?}", registered_circuits).as_bytes()).to_vec(),
-    };
-
-    BATCH_OPERATIONS.save(deps.storage, batch_op.id, &batch_op)?;
-
-    Ok(Response::new()
-        .add_attribute("method", "register_circuit_batch")
-        .add_attribute("batch_id", batch_op.id.to_string())
-        .add_attribute("circuits_registered", registered_circuits.len().to_string())
-        .add_attribute("total_gas", total_gas.to_string()))
-}
-
-// Enhanced proof submission with enterprise features
-pub fn execute_submit_proof(
-    deps: DepsMut,
-    env: Env,
-    info: MessageInfo,
-    circuit_id: String,
-    public_inputs: Vec<String>,
-    proof: String,
-) -> Result<Response, ContractError> {
-    let mut config = CONFIG.load(deps.storage)?;
-
-    // Enhanced proof validation
-    validate_proof(&proof)
-        .map_err(|e| ContractError::InvalidProof { error: e.to_string() })?;
-
-    // Check if circuit exists and is active
-    let circuit = CIRCUITS.load(deps.storage, &circuit_id)
-        .map_err(|_| ContractError::CircuitNotFound { circuit_id: circuit_id.clone() })?;
-
-    if !circuit.active {
-        return Err(ContractError::CircuitDeactivated { circuit_id });
-    }
-
-    // Validate public inputs count
-    if public_inputs.len() > circuit.max_public_inputs as usize {
-        return Err(ContractError::TooManyPublicInputs { 
-            max: circuit.max_public_inputs,
-            provided: public_inputs.len() as u32,
-        });
-    }
-
-    // Generate proof ID with enhanced entropy
-    let proof_id = format!("proof_{}_{}_{}", 
-        circuit_id, 
-        config.total_proofs + 1,
-        env.block.height
-    );
-
-    // Verify the proof with performance tracking
-    let start_time = env.block.time.seconds();
-    let verification_result = verify_proof(&circuit.verification_key, &public_inputs, &proof)?;
-    let verification_time = env.block.time.seconds() - start_time;
-
-    let proof_record = Proof {
-        proof_id: proof_id.clone(),
-        circuit_id: circuit_id.clone(),
-        submitter: info.sender.clone(),
-        public_inputs: public_inputs.clone(),
-        proof: proof.clone(),
-        verified: verification_result,
-        submitted_at: env.block.time.seconds(),
-        verified_at: if verification_result { Some(env.block.time.seconds()) } else { None },
-        // Enhanced fields
-        gas_used: circuit.gas_estimate,
-        verification_time,
-        proof_size: proof.len() as u32,
-        public_input_hash: Sha256::digest(format!("{:?}", public_inputs).as_bytes()).to_vec(),
-        nullifier_hash: if verification_result { 
-            Some(Sha256::digest(format!("{}{}", proof_id, info.sender).as_bytes()).to_vec())
-        } else { None },
-        metadata: HashMap::new(),
-    };
-
-    PROOFS.save(deps.storage, &proof_id, &proof_record)?;
-    CIRCUIT_PROOFS.save(deps.storage, (&circuit_id, &proof_id), &true)?;
-    
-    config.total_proofs += 1;
-    CONFIG.save(deps.storage, &config)?;
-
-    // Update circuit performance metrics
-    update_circuit_metrics(deps.storage, &circuit_id, verification_time, verification_result)?;
-
-    let mut response = Response::new()
-        .add_attribute("method", "submit_proof")
-        .add_attribute("proof_id", proof_id)
-        .add_attribute("circuit_id", circuit_id)
-        .add_attribute("verified", verification_result.to_string())
-        .add_attribute("verification_time", verification_time.to_string())
-        .add_attribute("gas_used", circuit.gas_estimate.to_string());
-
-    if verification_result {
-        response = response.add_attribute("status", "verified");
-    } else {
-        response = response.add_attribute("status", "verification_failed");
-    }
-
-    Ok(response)
-}
-
-// Emergency pause functionality
-pub fn execute_emergency_pause(
-    deps: DepsMut,
-    env: Env,
-    info: MessageInfo,
-) -> Result<Response, ContractError> {
-    crate::access_control::require_role(deps.as_ref(), "EMERGENCY_ADMIN", &info.sender)?;
-
-    let mut config = CONFIG.load(deps.storage)?;
-    config.emergency_pause_enabled = true;
-    CONFIG.save(deps.storage, &config)?;
-
-    // Log emergency pause
-    let audit_entry = AuditLogEntry {
-        id: get_next_audit_id(deps.storage)?,
-        event_type: "emergency_pause".to_string(),
-        actor: info.sender.to_string(),
-        target: "contract".to_string(),
-        timestamp: env.block.time.seconds(),
-        details: "Emergency pause activated".to_string(),
-        block_height: env.block.height,
-        tx_hash: "".to_string(),
-        risk_score: 100, // Highest risk score
-        compliance_flags: vec!["EMERGENCY_PAUSE".to_string()],
-    };
-    AUDIT_LOG.save(deps.storage, audit_entry.id, &audit_entry)?;
-
-    Ok(Response::new()
-        .add_attribute("method", "emergency_pause")
-        .add_attribute("activated_by", info.sender)
-        .add_attribute("timestamp", env.block.time.seconds().to_string()))
-}
-
-// Helper functions for enterprise features
-fn check_rate_limit(
-    storage: &dyn cosmwasm_std::Storage,
-    sender: &Addr,
-    env: &Env,
-) -> Result<(), ContractError> {
-    let current_time = env.block.time.seconds();
-    let rate_limit_key = format!("rate_limit_{}", sender);
-    
-    if let Ok(last_request) = RATE_LIMITS.load(storage, &rate_limit_key) {
-        if current_time - last_request < RATE_LIMIT_WINDOW {
-            return Err(ContractError::RateLimitExceeded {
-                retry_after: RATE_LIMIT_WINDOW - (current_time - last_request),
-            });
-        }
-    }
-    
-    RATE_LIMITS.save(storage, &rate_limit_key, &current_time)?;
-    Ok(())
-}
-
-fn get_message_type(msg: &ExecuteMsg) -> String {
-    match msg {
-        ExecuteMsg::RegisterCircuit { .. } => "register_circuit".to_string(),
-        ExecuteMsg::SubmitProof { .. } => "submit_proof".to_string(),
-        ExecuteMsg::SubmitProofBatch { .. } => "submit_proof_batch".to_string(),
-        ExecuteMsg::EmergencyPause { .. } => "emergency_pause".to_string(),
-        _ => "unknown".to_string(),
-    }
-}
-
-fn get_message_target(msg: &ExecuteMsg) -> String {
-    match msg {
-        ExecuteMsg::RegisterCircuit { circuit_id, .. } => circuit_id.clone(),
-        ExecuteMsg::SubmitProof { circuit_id, .. } => circuit_id.clone(),
-        _ => "contract".to_string(),
-    }
-}
-
-fn calculate_risk_score(msg: &ExecuteMsg) -> u32 {
-    match msg {
-        ExecuteMsg::EmergencyPause { .. } => 100,
-        ExecuteMsg::UpdateAdmin { .. } => 90,
-        ExecuteMsg::GrantRole { .. } => 80,
-        ExecuteMsg::RegisterCircuit { .. } => 50,
-        ExecuteMsg::SubmitProof { .. } => 20,
-        _ => 10,
-    }
-}
-
-fn calculate_gas_estimate(circuit_type: &str) -> u64 {
-    match circuit_type {
-        "groth16" => 100_000,
-        "plonk" => 150_000,
-        "stark" => 200_000,
-        "bulletproofs" => 300_000,
-        _ => 100_000,
-    }
-}
-
-fn get_security_level(circuit_type: &str) -> u32 {
-    match circuit_type {
-        "groth16" => 128,
-        "plonk" => 128,
-        "stark" => 256,
-        "bulletproofs" => 128,
-        _ => 128,
-    }
-}
-
-fn get_supported_features(circuit_type: &str) -> Vec<String> {
-    match circuit_type {
-        "groth16" => vec!["zkSNARK".to_string(), "universal_setup".to_string()],
-        "plonk" => vec!["zkSNARK".to_string(), "universal_setup".to_string(), "lookups".to_string()],
-        "stark" => vec!["zkSTARK".to_string(), "post_quantum".to_string()],
-        "bulletproofs" => vec!["range_proofs".to_string(), "inner_product".to_string()],
-        _ => vec![],
-    }
-}
-
-fn get_max_public_inputs(circuit_type: &str) -> u32 {
-    match circuit_type {
-        "groth16" => 1000,
-        "plonk" => 10000,
-        "stark" => 100000,
-        "bulletproofs" => 100,
-        _ => 1000,
-    }
-}
-
-fn get_next_audit_id(storage: &dyn cosmwasm_std::Storage) -> Result<u64, ContractError> {
-    let mut max_id = 0u64;
-    
-    for result in AUDIT_LOG.range(storage, None, None, Order::Ascending) {
-        let (id, _) = result?;
-        if id > max_id {
-            max_id = id;
-        }
-    }
-    
-    Ok(max_id + 1)
-}
-
-fn get_next_batch_id(storage: &dyn cosmwasm_std::Storage) -> Result<u64, ContractError> {
-    let mut max_id = 0u64;
-    
-    for result in BATCH_OPERATIONS.range(storage, None, None, Order::Ascending) {
-        let (id, _) = result?;
-        if id > max_id {
-            max_id = id;
-        }
-    }
-    
-    Ok(max_id + 1)
-}
-
-fn update_circuit_metrics(
-    storage: &mut dyn cosmwasm_std::Storage,
-    circuit_id: &str,
-    verification_time: u64,
-    success: bool,
-) -> Result<(), ContractError> {
-    let mut circuit = CIRCUITS.load(storage, circuit_id)?;
-    
-    // Update performance metrics
-    let total_verifications = circuit.performance_metrics
-        .get("total_verifications")
-        .unwrap_or(&0u64) + 1;
-    
-    let successful_verifications = circuit.performance_metrics
-        .get("successful_verifications")
-        .unwrap_or(&0u64) + if success { 1 } else { 0 };
-    
-    let total_verification_time = circuit.performance_metrics
-        .get("total_verification_time")
-        .unwrap_or(&0u64) + verification_time;
-    
-    circuit.performance_metrics.insert("total_verifications".to_string(), total_verifications);
-    circuit.performance_metrics.insert("successful_verifications".to_string(), successful_verifications);
-    circuit.performance_metrics.insert("total_verification_time".to_string(), total_verification_time);
-    circuit.performance_metrics.insert("average_verification_time".to_string(), 
-        total_verification_time / total_verifications);
-    circuit.performance_metrics.insert("success_rate".to_string(), 
-        (successful_verifications * 100) / total_verifications);
-    
-    CIRCUITS.save(storage, circuit_id, &circuit)?;
-    Ok(())
-}
-
-// Continue with other functions...
-// Additional enterprise functions would be implemented here
\ No newline at end of file
@@ -3,7 +3,7 @@
 /// This module provides utilities to ensure deterministic behavior in smart contracts.
 /// It analyzes code patterns that could lead to non-deterministic execution.
 
-use cosmwasm_std::{Env, BlockInfo, MessageInfo, Timestamp};
+use cosmwasm_std::{Env, BlockInfo, MessageInfo, Timestamp, Uint256};
 use std::collections::BTreeMap; // Use BTreeMap instead of HashMap for deterministic iteration
 use crate::error::ContractError;
 
@@ -192,6 +192,73 @@ pub fn safe_arithmetic_div(a: u64, b: u64) -> Result<u64, ContractError> {
     Ok(a / b)
 }
 
+/// 256-bit checked arithmetic for ZK field elements (BN254/BLS12-381 public
+/// inputs don't fit in a u64). Mirrors the `safe_arithmetic_*` API above.
+pub fn safe_arithmetic_add_256(a: Uint256, b: Uint256) -> Result<Uint256, ContractError> {
+    a.checked_add(b).map_err(|_| ContractError::IntegerOverflow {
+        operation: "addition".to_string(),
+        operands: format!("{} + {}", a, b),
+    })
+}
+
+pub fn safe_arithmetic_sub_256(a: Uint256, b: Uint256) -> Result<Uint256, ContractError> {
+    a.checked_sub(b).map_err(|_| ContractError::IntegerUnderflow {
+        operation: "subtraction".to_string(),
+        operands: format!("{} - {}", a, b),
+    })
+}
+
+pub fn safe_arithmetic_mul_256(a: Uint256, b: Uint256) -> Result<Uint256, ContractError> {
+    a.checked_mul(b).map_err(|_| ContractError::IntegerOverflow {
+        operation: "multiplication".to_string(),
+        operands: format!("{} * {}", a, b),
+    })
+}
+
+pub fn safe_arithmetic_div_256(a: Uint256, b: Uint256) -> Result<Uint256, ContractError> {
+    if b.is_zero() {
+        return Err(ContractError::DivisionByZero {});
+    }
+    Ok(a / b)
+}
+
+/// Modular exponentiation (`base^exp mod modulus`) via square-and-multiply:
+/// at each step square the accumulator mod `modulus`, then fold in `base`
+/// whenever the next exponent bit is set, consuming `exp` from LSB to MSB.
+/// All reductions go through `checked_mul`/`%` so intermediate overflow is
+/// caught instead of panicking in debug builds. `modulus == 0` is rejected;
+/// `mod_exp(_, 0, m) = 1 % m`.
+pub fn mod_exp(base: Uint256, exp: Uint256, modulus: Uint256) -> Result<Uint256, ContractError> {
+    if modulus.is_zero() {
+        return Err(ContractError::DivisionByZero {});
+    }
+    if modulus == Uint256::one() {
+        return Ok(Uint256::zero());
+    }
+
+    let mut result = Uint256::one();
+    let mut base = base % modulus;
+    let mut exp = exp;
+    let two = Uint256::from(2u8);
+
+    while !exp.is_zero() {
+        if exp % two == Uint256::one() {
+            result = result.checked_mul(base).map_err(|_| ContractError::IntegerOverflow {
+                operation: "mod_exp multiply".to_string(),
+                operands: format!("{} * {}", result, base),
+            })? % modulus;
+        }
+
+        exp = exp / two;
+        base = base.checked_mul(base).map_err(|_| ContractError::IntegerOverflow {
+            operation: "mod_exp squaring".to_string(),
+            operands: format!("{} * {}", base, base),
+        })? % modulus;
+    }
+
+    Ok(result)
+}
+
 /// Generate deterministic IDs
 pub fn generate_deterministic_id(prefix: &str, env: &Env, info: &MessageInfo) -> String {
     format!(
@@ -234,6 +301,36 @@ mod tests {
         assert!(safe_arithmetic_div(10, 0).is_err());
     }
 
+    #[test]
+    fn test_safe_arithmetic_256() {
+        let a = Uint256::from(5u128);
+        let b = Uint256::from(3u128);
+
+        assert_eq!(safe_arithmetic_add_256(a, b).unwrap(), Uint256::from(8u128));
+        assert_eq!(safe_arithmetic_sub_256(a, b).unwrap(), Uint256::from(2u128));
+        assert_eq!(safe_arithmetic_mul_256(a, b).unwrap(), Uint256::from(15u128));
+        assert_eq!(safe_arithmetic_div_256(a, b).unwrap(), Uint256::one());
+
+        assert!(safe_arithmetic_sub_256(b, a).is_err());
+        assert!(safe_arithmetic_div_256(a, Uint256::zero()).is_err());
+    }
+
+    #[test]
+    fn test_mod_exp() {
+        // 4^13 mod 497 = 445 (textbook square-and-multiply example)
+        let base = Uint256::from(4u128);
+        let exp = Uint256::from(13u128);
+        let modulus = Uint256::from(497u128);
+
+        assert_eq!(mod_exp(base, exp, modulus).unwrap(), Uint256::from(445u128));
+
+        // Anything to the zero power is 1 mod m.
+        assert_eq!(mod_exp(base, Uint256::zero(), modulus).unwrap(), Uint256::one());
+
+        // Zero modulus is rejected.
+        assert!(mod_exp(base, exp, Uint256::zero()).is_err());
+    }
+
     #[test]
     fn test_deterministic_sorting() {
         let mut addresses = vec![
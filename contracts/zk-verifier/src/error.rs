@@ -51,6 +51,9 @@ pub enum ContractError {
     #[error("Issuer is deactivated: {address}")]
     IssuerDeactivated { address: String },
 
+    #[error("Issuer authorization expired at {expired_at}: {address}")]
+    IssuerExpired { address: String, expired_at: u64 },
+
     #[error("Unauthorized circuit type: {circuit_type}, authorized: {authorized:?}")]
     UnauthorizedCircuitType { circuit_type: String, authorized: Vec<String> },
 
@@ -60,6 +63,9 @@ pub enum ContractError {
     #[error("Proposal already executed: {proposal_id}")]
     ProposalAlreadyExecuted { proposal_id: u64 },
 
+    #[error("Proposal {proposal_id} is already queued behind its timelock delay")]
+    ProposalAlreadyScheduled { proposal_id: u64 },
+
     #[error("Voting period ended: {proposal_id}")]
     VotingPeriodEnded { proposal_id: u64 },
 
@@ -69,12 +75,88 @@ pub enum ContractError {
     #[error("Voter {voter} has already voted on proposal {proposal_id}")]
     AlreadyVoted { proposal_id: u64, voter: String },
 
+    #[error("Voter {voter} has not voted on proposal {proposal_id}")]
+    VoteNotFound { proposal_id: u64, voter: String },
+
     #[error("Proposal failed to pass")]
     ProposalFailed {},
 
     #[error("Governance not enabled")]
     GovernanceNotEnabled {},
 
+    #[error("Proposal {proposal_id} did not reach quorum: {participating_weight} participating weight < {quorum_threshold} required")]
+    QuorumNotReached { proposal_id: u64, participating_weight: u64, quorum_threshold: u64 },
+
+    #[error("Voter {voter} is locked out from voting until block {unlock_height}")]
+    VoteLockedOut { voter: String, unlock_height: u64 },
+
+    #[error("Proposal {proposal_id} has no scheduled timelock transaction to cancel")]
+    ProposalNotScheduled { proposal_id: u64 },
+
+    #[error("Voting period {provided} out of bounds: min {min}, max {max}")]
+    VotingPeriodOutOfBounds { provided: u64, min: u64, max: u64 },
+
+    #[error("Incorrect proposal deposit: required {required}, provided {provided}")]
+    IncorrectProposalDeposit { required: String, provided: String },
+
+    #[error("Proposal {proposal_id} deposit already refunded")]
+    DepositAlreadyRefunded { proposal_id: u64 },
+
+    #[error("Proposal {proposal_id} has insufficient multisig approvals: required {required}, provided {provided}")]
+    InsufficientApprovals { proposal_id: u64, required: u64, provided: u64 },
+
+    #[error("Proposal {proposal_id} voting period has not ended, nothing to refund yet")]
+    DepositNotRefundable { proposal_id: u64 },
+
+    #[error("Proposal {proposal_id} is still in Draft: all required signatories must sign off before voting opens")]
+    ProposalStillInDraft { proposal_id: u64 },
+
+    #[error("{signatory} is not a required signatory on proposal {proposal_id}")]
+    SignatoryNotFound { proposal_id: u64, signatory: String },
+
+    #[error("{signatory} is already a required signatory on proposal {proposal_id}")]
+    SignatoryAlreadyAdded { proposal_id: u64, signatory: String },
+
+    #[error("{signatory} has already signed off on proposal {proposal_id}")]
+    AlreadySignedOff { proposal_id: u64, signatory: String },
+
+    #[error("Signatories can only be added to or removed from a proposal still in Draft: {proposal_id}")]
+    ProposalNotInDraft { proposal_id: u64 },
+
+    #[error("SelectIssuerCommittee requires at least one candidate and 0 < k <= candidates.len()")]
+    InvalidCommitteeSelection {},
+
+    #[error("Governance is enabled: call SubmitGovernanceProposal instead of executing this action directly")]
+    GovernanceRequired {},
+
+    #[error("Only dao_address or a GOVERNANCE_ROLE member may submit governance proposals")]
+    UnauthorizedProposer {},
+
+    #[error("Randomness provider not configured")]
+    RandomnessProviderNotConfigured {},
+
+    #[error("Unauthorized randomness provider: {provider}")]
+    UnauthorizedRandomnessProvider { provider: String },
+
+    #[error("No pending randomness request for proposal {proposal_id}")]
+    RandomnessRequestNotFound { proposal_id: u64 },
+
+    #[error("Randomness request for proposal {proposal_id} already fulfilled")]
+    RandomnessAlreadyFulfilled { proposal_id: u64 },
+
+    #[error("Randomness beacon must be exactly 32 bytes, got {len}")]
+    InvalidRandomnessLength { len: usize },
+
+    // PLONK/Halo2 Errors
+    #[error("Invalid PLONK/Halo2 verification key structure")]
+    InvalidPlonkVerificationKey {},
+
+    #[error("Invalid PLONK/Halo2 proof structure")]
+    InvalidPlonkProof {},
+
+    #[error("Unsupported proof system for circuit {circuit_id}")]
+    UnsupportedProofSystem { circuit_id: String },
+
     // Access Control Errors
     #[error("Missing role: {role} for account: {account}")]
     MissingRole { role: String, account: String },
@@ -101,6 +183,12 @@ pub enum ContractError {
     #[error("Timelock transaction not found: {id}")]
     TimelockNotFound { id: u64 },
 
+    #[error("Timelock governance configuration is frozen")]
+    TimelockFrozen {},
+
+    #[error("Timelock transaction {id} expired at {expired_at}")]
+    TimelockExpired { id: u64, expired_at: u64 },
+
     // Multisig Errors
     #[error("Multisig not enabled")]
     MultisigNotEnabled {},
@@ -136,4 +224,133 @@ pub enum ContractError {
 
     #[error("Data integrity check failed")]
     IntegrityError {},
+
+    #[error("Invalid submitter signature")]
+    InvalidSignature {},
+
+    #[error("Nullifier already spent for circuit {circuit_id}")]
+    NullifierAlreadySpent { circuit_id: String },
+
+    #[error("Nullifier index {index} out of range for public_inputs of length {len}")]
+    NullifierIndexOutOfRange { index: u32, len: usize },
+
+    #[error("Unsupported proof protocol: {protocol}, expected groth16")]
+    UnsupportedProtocol { protocol: String },
+
+    #[error("Curve mismatch: {curve}, expected bn128/bn254")]
+    CurveMismatch { curve: String },
+
+    #[error("Public input count mismatch: expected {expected}, got {got}")]
+    PublicInputCountMismatch { expected: usize, got: usize },
+
+    #[error("Commitment index {index} out of range for public_inputs of length {len}")]
+    CommitmentIndexOutOfRange { index: u32, len: usize },
+
+    #[error("Poseidon commitment mismatch")]
+    CommitmentMismatch {},
+
+    // Fee Errors
+    #[error("Insufficient fee: required {required}, provided {provided}")]
+    InsufficientFee { required: String, provided: String },
+
+    #[error("Insufficient collected fees: requested {requested}, available {available}")]
+    InsufficientFeeBalance { requested: String, available: String },
+
+    // Cross-Chain / Guardian Errors
+    #[error("Cross-chain transaction not found: {tx_id}")]
+    CrossChainTxNotFound { tx_id: u64 },
+
+    #[error("Cross-chain transaction {tx_id} already executed")]
+    CrossChainTxAlreadyExecuted { tx_id: u64 },
+
+    #[error("Guardian set not found: {index}")]
+    GuardianSetNotFound { index: u32 },
+
+    #[error("Guardian set already exists: {index}")]
+    GuardianSetAlreadyExists { index: u32 },
+
+    #[error("Guardian set {index} has expired")]
+    GuardianSetExpired { index: u32 },
+
+    #[error("Guardian signatures must be strictly ascending by index")]
+    GuardianSignaturesOutOfOrder {},
+
+    #[error("Unknown guardian index: {index}")]
+    UnknownGuardianIndex { index: u8 },
+
+    #[error("Insufficient guardian signatures: required {required}, provided {provided}")]
+    InsufficientGuardianSignatures { required: u32, provided: u32 },
+
+    // Sparse Merkle Tree Errors
+    #[error("Merkle leaf index {index} out of range for depth {depth} (max {max})")]
+    MerkleIndexOutOfRange { index: u64, depth: u32, max: u64 },
+
+    // Metrics Errors
+    #[error("Metric {metric} already registered as {registered}, cannot re-emit as {requested}")]
+    MetricKindMismatch { metric: String, registered: String, requested: String },
+
+    // Migration Errors
+    #[error("Cannot migrate contract {found}, expected {expected}")]
+    MigrationContractMismatch { expected: String, found: String },
+
+    #[error("Refusing to downgrade state from version {stored} to {target}")]
+    MigrationDowngrade { stored: u64, target: u64 },
+
+    // Revocation Errors
+    #[error("Circuit {circuit_id} has no revocation accumulator configured")]
+    RevocationNotConfigured { circuit_id: String },
+
+    #[error("Revocation index {index} out of range for public_inputs of length {len}")]
+    RevocationIndexOutOfRange { index: u32, len: usize },
+
+    #[error("Credential {credential_index} revoked or witness stale for circuit {circuit_id}")]
+    CredentialRevoked { circuit_id: String, credential_index: u32 },
+
+    // Reward Errors
+    #[error("No GOVERNANCE_ROLE or ADMIN_ROLE members to distribute rewards to")]
+    NoRewardRecipients {},
+
+    #[error("No {denom} fees collected to claim")]
+    NoFeesToClaim { denom: String },
+
+    // Issuer Bond Errors
+    #[error("Issuer bond already escrowed for {address}; call WithdrawBond before re-adding")]
+    IssuerBondAlreadyEscrowed { address: String },
+
+    #[error("No issuer bond escrowed for {address}")]
+    IssuerBondNotFound { address: String },
+
+    #[error("Issuer bond for {address} is not withdrawable until {withdrawable_at}")]
+    BondNotWithdrawable { address: String, withdrawable_at: u64 },
+
+    #[error("Issuer {address} is still active; call RemoveIssuer before WithdrawBond")]
+    IssuerStillActive { address: String },
+
+    #[error("Guardian set must have at least one guardian")]
+    EmptyGuardianSet {},
+
+    #[error("Attestation already processed: chain {emitter_chain}, address {emitter_address}, sequence {sequence}")]
+    AttestationAlreadyProcessed { emitter_chain: u32, emitter_address: String, sequence: u64 },
+
+    // Rate Limiting Errors
+    #[error("Rate limit exceeded, retry after {retry_after} seconds")]
+    RateLimitExceeded { retry_after: u64 },
+
+    // Validator Set Errors
+    #[error("Validator set must have at least one validator")]
+    EmptyValidatorSet {},
+
+    #[error("Validator set activation height {activate_at_height} must be after the current height {current_height}")]
+    ValidatorSetActivationNotInFuture { activate_at_height: u64, current_height: u64 },
+
+    // DID Attestation Errors
+    #[error("No attestation {attestation_id} for subject {subject_did}")]
+    DidAttestationNotFound { subject_did: String, attestation_id: String },
+
+    // Finality Gadget Errors
+    #[error("{voter} already voted {phase} for seq {seq}")]
+    AlreadyVotedFinality { seq: u64, phase: String, voter: String },
+
+    #[error("seq {seq} must reach quorum in {expected_phase} before voting {phase}")]
+    FinalityPhaseOutOfOrder { seq: u64, phase: String, expected_phase: String },
 }
\ No newline at end of file
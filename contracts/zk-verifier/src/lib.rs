@@ -5,6 +5,9 @@ pub mod state;
 pub mod verifier;
 pub mod access_control;
 pub mod determinism_audit;
-pub mod encryption;
+pub mod permit;
+pub mod plonk_verifier;
+pub mod proof_system;
+pub mod revocation;
 
-pub use crate::error::ContractError;
\ No newline at end of file
+pub use crate::error::ContractError;
@@ -1,6 +1,30 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Addr;
-use crate::state::ProposalType;
+use cosmwasm_std::{Addr, Binary, Coin, CosmosMsg, Decimal};
+use crate::state::{ProposalType, CommitmentPolicy, TimelockStatus, ProposalStatus, SortOrder, ProposalDepositConfig, ProofSystem, VoteChoice};
+
+/// Codec a submitted proof (and the circuit's stored verification key) is
+/// encoded in. Mirrors `crate::verifier::ProofEncoding`.
+#[cw_serde]
+pub enum ProofEncoding {
+    /// Verbose snarkjs JSON (decimal/hex limb arrays), hand-parsed from a
+    /// `serde_json::Value`.
+    Json,
+    /// Compact arkworks compressed-affine point encoding.
+    CompressedBinary,
+    /// `crate::verifier::StructuredProof`/`StructuredVerifyingKey`,
+    /// serde-derived types serialized to `Binary` — no hand-parsed JSON,
+    /// no fixed byte layout, and (like every other encoding here) no
+    /// field ever resolves to a float, since every field element is a
+    /// decimal or `0x`-hex string.
+    Structured,
+}
+
+/// One proof in a `ExecuteMsg::SubmitProofs` or `ExecuteMsg::SubmitProofBatch` batch.
+#[cw_serde]
+pub struct ProofEntry {
+    pub public_inputs: Vec<String>,
+    pub proof: String,
+}
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -10,6 +34,55 @@ pub struct InstantiateMsg {
     pub multisig_config: Option<MultisigConfig>,
     pub timelock_enabled: Option<bool>,
     pub min_timelock_delay: Option<u64>,
+    /// Addresses granted `access_control::EXECUTOR_ROLE` at instantiation,
+    /// in addition to `admin`. Omit for no additional default executors.
+    pub executor_allowlist: Option<Vec<String>>,
+    /// Native-token fee `RegisterCircuit` must carry in `info.funds`.
+    /// Omit for free registration.
+    pub registration_fee: Option<Coin>,
+    /// Fallback governance voting weight for accounts with no explicit
+    /// `VOTING_POWER` entry. Omit for one-account-one-vote (weight 1).
+    pub default_voting_power: Option<u64>,
+    /// Minimum total participating weight for a proposal to be
+    /// executable. Omit for no quorum requirement (0).
+    pub default_quorum_threshold: Option<u64>,
+    /// Minimum `votes_for` weight for a proposal to pass once quorum is
+    /// met. Omit for a bare `votes_for > 0` requirement (1).
+    pub default_pass_threshold: Option<u64>,
+    /// Minimum fraction of eligible voting weight that must participate,
+    /// an additional gate alongside `default_quorum_threshold`'s absolute
+    /// weight check. Omit for no fractional quorum requirement (0).
+    pub default_quorum: Option<Decimal>,
+    /// Minimum fraction of Yes out of Yes+No votes required to pass, an
+    /// additional gate alongside `default_pass_threshold`'s absolute
+    /// weight check. Omit for no fractional approval requirement (0).
+    pub default_threshold: Option<Decimal>,
+    /// Fallback `grace_period` applied to `ScheduleTimelockTransaction`
+    /// calls that don't specify one. Omit for no default (transactions
+    /// with no explicit `grace_period` never expire).
+    pub default_timelock_grace_period: Option<u64>,
+    /// Default voting window for `SubmitGovernanceProposal`, and the
+    /// ceiling a per-proposal override may not exceed. Omit for 7 days.
+    pub voting_period_seconds: Option<u64>,
+    /// Floor a per-proposal `voting_period` override may not go below.
+    /// Omit for 1 hour.
+    pub min_voting_period_seconds: Option<u64>,
+    /// Deposit `SubmitGovernanceProposal` must escrow to deter spam
+    /// proposals, and the policy for refunding it once voting closes. Omit
+    /// for free proposals.
+    pub proposal_deposit: Option<ProposalDepositConfig>,
+    /// Address trusted to deliver the randomness beacon a
+    /// `ProposalType::SelectIssuerCommittee` proposal waits on via
+    /// `ExecuteMsg::ReceiveRandomness`. Omit if that proposal type won't be
+    /// used.
+    pub randomness_provider: Option<String>,
+    /// Bond `AddIssuer` must escrow in `info.funds`, and the
+    /// slashing/withdrawal policy over that escrow. Omit for unbonded
+    /// issuer onboarding.
+    pub issuer_bond: Option<crate::state::IssuerBondConfig>,
+    /// Per-submitter token-bucket limit on `SubmitProof`. Omit for
+    /// unlimited submissions.
+    pub rate_limit: Option<crate::state::RateLimitConfig>,
 }
 
 use crate::state::MultisigConfig;
@@ -21,8 +94,32 @@ pub enum ExecuteMsg {
         circuit_id: String,
         verification_key: String,
         circuit_type: String,
+        /// Index into a proof's `public_inputs` carrying the nullifier, for
+        /// circuits that want one-time-use anti-replay enforcement on
+        /// `SubmitProof`. Omit for circuits with no nullifier semantics.
+        nullifier_index: Option<u32>,
+        /// Poseidon public-input-binding policy, for circuits that want
+        /// the contract to recompute and check a commitment over the
+        /// submitted public inputs (see `CommitmentPolicy`).
+        commitment_policy: Option<CommitmentPolicy>,
+        /// Index into a proof's `public_inputs` carrying the credential
+        /// index a revocable circuit's non-revocation accumulator witness
+        /// is about. Must be paired with `revocation_witness_index`; omit
+        /// both for circuits with no revocation support.
+        revocation_index: Option<u32>,
+        /// Index into a proof's `public_inputs` carrying the decimal
+        /// `Uint256` non-revocation witness paired with `revocation_index`
+        /// (see `revocation::verify_membership`).
+        revocation_witness_index: Option<u32>,
+        /// Proof system `verification_key` and future `SubmitProof` calls
+        /// against this circuit are encoded in. Omit for `Groth16`, the
+        /// only system with a cached `PreparedVerifyingKey`; `Plonk`/`Halo2`
+        /// are verified via `crate::plonk_verifier` instead.
+        proof_system: Option<ProofSystem>,
     },
-    /// Deactivate an existing circuit
+    /// Deactivate an existing circuit. Rejected once
+    /// `Config::governance_enabled` is set — submit a
+    /// `ProposalType::DeactivateCircuit` proposal instead.
     DeactivateCircuit {
         circuit_id: String,
     },
@@ -32,7 +129,55 @@ pub enum ExecuteMsg {
         public_inputs: Vec<String>,
         proof: String,
     },
-    /// Update contract admin
+    /// Submit a proof with `Proof::submitter` set to `permit`'s recovered
+    /// signer instead of `info.sender`, so a relayer can submit on an
+    /// issuer's or the admin's behalf without holding their tx-signing key.
+    /// `permit` must authorize `crate::permit::PermitAction::SubmitProof`
+    /// against this contract, and its recovered signer must be the admin or
+    /// a registered, active, non-expired issuer.
+    SubmitProofWithPermit {
+        permit: crate::permit::Permit,
+        circuit_id: String,
+        public_inputs: Vec<String>,
+        proof: String,
+    },
+    /// Submit a proof using an explicit codec, accepting a compact
+    /// compressed-binary encoding in addition to JSON (see
+    /// `crate::verifier::ProofEncoding`) to cut storage/gas. The stored
+    /// circuit's `verification_key` must use the same encoding.
+    SubmitProofEncoded {
+        circuit_id: String,
+        public_inputs: Vec<String>,
+        proof: Binary,
+        encoding: ProofEncoding,
+    },
+    /// Submit several proofs against the same circuit in one message. For a
+    /// `ProofSystem::Groth16` circuit this draws non-interactive
+    /// transcript-hash scalars `r_i` and checks the whole batch as a single
+    /// randomized-linear-combination pairing equation (one final
+    /// exponentiation instead of one per proof) — a single bad proof fails
+    /// the entire batch, same as before. For any other proof system, which
+    /// can't be combined this way, this falls back to independent
+    /// per-proof verification identical to `SubmitProofs`. Either way the
+    /// response carries a `verification_mode` attribute (`"aggregated"` or
+    /// `"per_proof"`) so a client can tell which path ran.
+    SubmitProofBatch {
+        circuit_id: String,
+        proofs: Vec<ProofEntry>,
+    },
+    /// Submit several proofs against the same circuit, each verified and
+    /// stored independently (unlike `SubmitProofBatch`'s aggregated
+    /// all-or-nothing check): one bad proof is recorded as unverified
+    /// without aborting the rest. Emits one `proof_result` event per entry
+    /// (`proof_id`, `verified`) plus `verified_count`/`rejected_count`
+    /// summary attributes, so a client submitting many credentials at once
+    /// can reconcile each outcome from a single transaction.
+    SubmitProofs {
+        circuit_id: String,
+        batch: Vec<ProofEntry>,
+    },
+    /// Update contract admin. Rejected once `Config::governance_enabled`
+    /// is set — submit a `ProposalType::UpdateAdmin` proposal instead.
     UpdateAdmin {
         new_admin: String,
     },
@@ -40,26 +185,187 @@ pub enum ExecuteMsg {
     AddIssuer {
         issuer_address: String,
         authorized_circuits: Vec<String>,
+        /// Block time after which this authorization lapses on its own,
+        /// enforced the same way a deactivated issuer is. Omit for an
+        /// authorization that never expires.
+        expires_at: Option<u64>,
     },
     /// Remove an issuer (admin only or governance)
     RemoveIssuer {
         issuer_address: String,
     },
-    /// Submit a governance proposal
+    /// Claim back the caller's remaining `ISSUER_BONDS` escrow once
+    /// `IssuerBond::withdrawable_at` has passed — set by `RemoveIssuer` to
+    /// `Config::issuer_bond`'s `withdrawal_delay` after removal. Any
+    /// address with an escrowed bond may call this directly; it isn't
+    /// gated to the admin.
+    WithdrawBond {},
+    /// Register a new guardian set, retiring the current one (if any)
+    /// immediately. `ADMIN_ROLE` only. `index` lets coordinated off-chain
+    /// guardians agree on an explicit set index ahead of time (e.g. to
+    /// align indices across multiple chains); omit it to auto-increment
+    /// from the current index as before. An explicit index that's already
+    /// occupied is rejected rather than silently overwritten.
+    RegisterGuardianSet {
+        pubkeys: Vec<Binary>,
+        #[serde(default)]
+        index: Option<u32>,
+    },
+    /// Ingest a credential proof already verified on another chain,
+    /// trusting `vaa.body.verified` on the strength of a guardian-set
+    /// quorum instead of re-verifying the proof here. Rejects replays of
+    /// an already-processed `(emitter_chain, emitter_address, sequence)`.
+    SubmitAttestedProof {
+        vaa: crate::state::ProofAttestation,
+    },
+    /// Relay an arbitrary `Vec<CosmosMsg>` under guardian quorum instead of
+    /// a single attested proof — e.g. releasing funds escrowed for another
+    /// chain, or applying a remote governance decision. Verifies `signatures`
+    /// over `(tx_id, msgs)` against `guardian_set_index` the same way
+    /// `SubmitAttestedProof` verifies a proof body, then dispatches `msgs`
+    /// exactly once per `tx_id`.
+    SubmitCrossChainTransaction {
+        tx_id: u64,
+        msgs: Vec<CosmosMsg>,
+        guardian_set_index: u32,
+        signatures: Vec<crate::state::GuardianSignature>,
+    },
+    /// Push a new point observation into `denom`'s
+    /// `crate::state::FilterEstimate`, alpha-beta-smoothing it in place.
+    /// `ADMIN_ROLE` only.
+    RecordGasPriceObservation {
+        denom: String,
+        observed_price: u128,
+    },
+    /// Drain every currently-`Ready` `ScheduleTimelockTransaction` entry,
+    /// up to `limit` (default 10). Callable by anyone — each drained
+    /// transaction still goes through `ExecuteTimelockTransaction`'s own
+    /// authorization and multisig checks against the caller, so cranking
+    /// never executes anything the caller couldn't execute directly.
+    CrankTimelockQueue {
+        limit: Option<u32>,
+    },
+    /// Alias `circuit_type` onto an existing proof-system backend
+    /// (`ProofSystemBackend`/`backend_for`), so `RegisterCircuit` callers
+    /// can declare a new circuit_type string without a contract upgrade.
+    /// Admin only. Does not add new verification code — `backend` must be
+    /// one of the variants this contract already implements a backend for.
+    RegisterProofSystem {
+        circuit_type: String,
+        backend: ProofSystem,
+    },
+    /// Submit a governance proposal. Once `Config::dao_address` is set,
+    /// only it or a `GOVERNANCE_ROLE` member may call this; with no
+    /// `dao_address` configured, submission stays open and
+    /// `Config::proposal_deposit` is the only anti-spam gate.
     SubmitGovernanceProposal {
         title: String,
         description: String,
         proposal_type: ProposalType,
+        /// Override `Config::voting_period_seconds` for this proposal.
+        /// Must fall within
+        /// `[Config::min_voting_period_seconds, Config::voting_period_seconds]`;
+        /// omit to use the default period as-is.
+        voting_period: Option<u64>,
+        /// Floor on the timelock delay this proposal is queued with once it
+        /// passes, in addition to `Config::min_timelock_delay` (the larger
+        /// of the two applies). Omit to use the contract-wide minimum as-is.
+        requested_delay: Option<u64>,
+        /// Required co-sponsors who must each call `SignOffProposal` before
+        /// voting opens, the spl-governance-style review/endorsement gate.
+        /// Omit or leave empty to skip `ProposalStatus::Draft` entirely and
+        /// open for voting immediately, the historical behavior.
+        #[serde(default)]
+        signatories: Vec<String>,
+        /// Ordered on-chain messages this proposal enacts on
+        /// `ExecuteProposal`, the spl-governance proposal-instruction
+        /// model — e.g. a `RegisterCircuit`, `AddIssuer`, `UpdateAdmin`, or
+        /// `GrantRole` call back into this same contract. Dispatched
+        /// alongside `proposal_type`'s effect, not instead of it. Omit or
+        /// leave empty for proposals that only carry the coarse
+        /// `proposal_type` change.
+        #[serde(default)]
+        instructions: Vec<CosmosMsg>,
     },
     /// Vote on a governance proposal
     VoteOnProposal {
         proposal_id: u64,
-        vote: bool, // true for yes, false for no
+        vote: VoteChoice,
+    },
+    /// Change a vote already cast on an open proposal. Subtracts the
+    /// voter's weight from its prior tally and re-applies it to the new
+    /// choice; fails once `voting_end` has passed, same as `VoteOnProposal`.
+    ChangeVote {
+        proposal_id: u64,
+        vote: VoteChoice,
+    },
+    /// Attach another required signatory to a proposal still in
+    /// `ProposalStatus::Draft`. Only the original proposer may call this.
+    AddSignatory {
+        proposal_id: u64,
+        signatory: String,
+    },
+    /// Drop a required signatory from a proposal still in
+    /// `ProposalStatus::Draft`. Only the original proposer may call this.
+    RemoveSignatory {
+        proposal_id: u64,
+        signatory: String,
+    },
+    /// Sign off as a required signatory on a proposal in
+    /// `ProposalStatus::Draft`. Once every attached signatory has signed
+    /// off, `voting_end` is set and the proposal transitions to
+    /// `ProposalStatus::Open`.
+    SignOffProposal {
+        proposal_id: u64,
+    },
+    /// Withdraw a vote cast on a still-open proposal, removing its
+    /// `VoteRecord` and rolling back the tally it applied. The voter may
+    /// then call `VoteOnProposal` again from a clean slate.
+    RelinquishVote {
+        proposal_id: u64,
+    },
+    /// Record a multisig safety-council approval of a proposal. Only a
+    /// `Config::multisig_config` signer may call this; each signer's
+    /// approval counts once no matter how many times they call it. When
+    /// multisig is enabled this is a second gate on execution, alongside
+    /// the DAO vote tally.
+    ApproveProposal {
+        proposal_id: u64,
     },
-    /// Execute a passed governance proposal
+    /// Execute a passed governance proposal. When `Config::timelock_enabled`
+    /// this doesn't apply the proposal's effect directly — it schedules an
+    /// `ApplyGovernanceProposal` timelock transaction and records its id on
+    /// the proposal, so members get the normal delay window to react.
     ExecuteProposal {
         proposal_id: u64,
     },
+    /// Internal-only: applies a passed proposal's effect once its timelock
+    /// transaction fires. Only callable by the contract itself (it's the
+    /// `CosmosMsg` `ExecuteProposal` schedules), never directly by a user.
+    ApplyGovernanceProposal {
+        proposal_id: u64,
+    },
+    /// Abort a proposal queued in the timelock before its delay elapses.
+    /// Same authorization as `CancelTimelockTransaction`: the original
+    /// proposer, or any `ADMIN_ROLE` holder.
+    CancelScheduledProposal {
+        proposal_id: u64,
+    },
+    /// Return a proposal's escrowed `Config::proposal_deposit`, per
+    /// `ProposalDepositConfig::refund_policy`, once voting has closed.
+    /// Callable by anyone (like `ClaimRewards`, the outcome is already
+    /// decided by this point) but errors if the deposit was already
+    /// refunded or if voting is still open.
+    RefundProposalDeposit {
+        proposal_id: u64,
+    },
+    /// Set an account's governance voting weight, used for
+    /// `votes_for`/`votes_against` tallying instead of the flat
+    /// `Config::default_voting_power`. `ADMIN_ROLE` only.
+    SetVotingPower {
+        account: String,
+        power: u64,
+    },
     /// Grant role to an account
     GrantRole {
         role: String,
@@ -70,11 +376,29 @@ pub enum ExecuteMsg {
         role: String,
         account: String,
     },
-    /// Schedule a timelock transaction
+    /// Re-point `role`'s admin role, i.e. the role allowed to grant/revoke
+    /// it. Callable only by the role's current admin (defaulting to
+    /// `ADMIN_ROLE`); lets that admin delegate management of `role` to a
+    /// different role without granting full `ADMIN_ROLE`.
+    SetRoleAdmin {
+        role: String,
+        admin_role: String,
+    },
+    /// Schedule a timelock transaction. Requires `PROPOSER_ROLE` and
+    /// `delay >= min_timelock_delay`. `msgs` are dispatched verbatim via
+    /// `Response::add_messages` once `ExecuteTimelockTransaction` runs
+    /// after the delay; a malformed message fails to deserialize here,
+    /// not after the delay has elapsed.
     ScheduleTimelockTransaction {
-        target_function: String,
-        params: String,
+        msgs: Vec<CosmosMsg>,
         delay: u64,
+        /// Per-transaction executor allowlist. Omit or pass an empty list
+        /// to allow any address to execute once ripe; otherwise only these
+        /// addresses or `EXECUTOR_ROLE` holders may execute it.
+        executors: Option<Vec<String>>,
+        /// Seconds after the transaction becomes ripe during which it may
+        /// still be executed. Omit for no expiration.
+        grace_period: Option<u64>,
     },
     /// Execute a timelock transaction
     ExecuteTimelockTransaction {
@@ -84,6 +408,103 @@ pub enum ExecuteMsg {
     ApproveTimelockTransaction {
         transaction_id: u64,
     },
+    /// Cancel a scheduled timelock transaction before it executes.
+    /// Callable by `ADMIN_ROLE` or the original proposer.
+    CancelTimelockTransaction {
+        transaction_id: u64,
+    },
+    /// Permanently freeze the governance configuration (`ADMIN_ROLE` only):
+    /// `min_timelock_delay`, `MultisigConfig`, and proposer/executor role
+    /// grants become immutable. Scheduling and executing timelock
+    /// transactions is unaffected.
+    FreezeTimelock {},
+    /// Withdraw collected `Config::registration_fee` funds to `recipient`.
+    /// `ADMIN_ROLE` only.
+    WithdrawFees {
+        recipient: String,
+        amount: Coin,
+    },
+    /// Revoke a credential from a revocable circuit's non-revocation
+    /// accumulator: folds `credential_index` out of the active member set,
+    /// rebuilds the accumulator value, and bumps its epoch so witnesses
+    /// computed before this call stop verifying. Callable only by the
+    /// circuit's `creator` or `Config::admin`.
+    RevokeCredential {
+        circuit_id: String,
+        credential_index: u32,
+    },
+    /// Set (or clear, with `fee: None`) the native-token fee
+    /// `ExecuteMsg::SubmitProof` must carry in `info.funds` for
+    /// `circuit_id`. `CIRCUIT_MANAGER_ROLE` only.
+    SetCircuitSubmissionFee {
+        circuit_id: String,
+        fee: Option<Coin>,
+    },
+    /// Update (or clear, with `None`) `Config::registration_fee`, the
+    /// contract-wide fee `ExecuteMsg::RegisterCircuit` must carry.
+    /// `ADMIN_ROLE` only, and rejected once `Config::governance_enabled`
+    /// is set — submit a `ProposalType::UpdateFees` proposal instead.
+    UpdateFees {
+        registration_fee: Option<Coin>,
+    },
+    /// Add `info.funds` directly to `COLLECTED_FEES`, one denom at a time,
+    /// without registering a circuit or submitting a proof. Callable by
+    /// anyone; later split among privileged accounts the same way
+    /// registration/submission fees are, via `ClaimRewards`.
+    Donate {},
+    /// Distribute the entire `denom` balance accrued in `COLLECTED_FEES`
+    /// (from registration and submission fees) equally among the current
+    /// `GOVERNANCE_ROLE` members, falling back to `ADMIN_ROLE` if
+    /// governance has none, via one `BankMsg::Send` per recipient.
+    /// Callable by anyone, since the fees were already collected from
+    /// past submissions; a remainder left over from integer division
+    /// stays in `COLLECTED_FEES` for the next claim.
+    ClaimRewards {
+        denom: String,
+    },
+    /// Callback delivering the beacon a `ProposalType::SelectIssuerCommittee`
+    /// proposal's execution requested from `Config::randomness_provider`.
+    /// Only that configured address may call this; `randomness` must be
+    /// exactly 32 bytes. Deterministically Fisher-Yates-shuffles
+    /// `candidates` seeded from the beacon and stores the first `k` as the
+    /// selected committee, so the result is fully auditable and replayable
+    /// from `(beacon, candidates)` alone — never from `env.block`.
+    ReceiveRandomness {
+        proposal_id: u64,
+        randomness: Binary,
+    },
+    /// Issue a credential attestation from `issuer_did` about
+    /// `subject_did`, stored under both `DID_ATTESTATIONS` (keyed by
+    /// subject, for "what does this DID hold") and
+    /// `ISSUER_DID_ATTESTATIONS` (keyed by issuer, for "what has this DID
+    /// issued"). `info.sender` must be the contract admin or an active,
+    /// non-expired issuer, same as `RegisterCircuit`; the DID strings
+    /// themselves are caller-supplied identifiers, not validated against
+    /// `info.sender`.
+    IssueDidAttestation {
+        issuer_did: String,
+        subject_did: String,
+        attestation_type: String,
+        data: Binary,
+    },
+    /// Mark a previously issued attestation revoked. Callable only by
+    /// `Config::admin` or the `issuer_did`'s on-chain signer that issued
+    /// it.
+    RevokeDidAttestation {
+        subject_did: String,
+        attestation_id: String,
+    },
+    /// Cast this validator's weighted vote for `DID_PROPAGATION_LOG[seq]`
+    /// at `phase`. Requires nonzero `contract::voting_power` under the
+    /// active `VALIDATOR_SETS` epoch (or `VOTING_POWER` if none has ever
+    /// been promoted), and requires the prior phase to already have
+    /// quorum for `PreCommit`/`Commit` (see `crate::state::HotstuffPhase`).
+    /// A `Commit`-phase vote that reaches quorum finalizes `seq` and every
+    /// earlier unfinalized seq.
+    VoteFinality {
+        seq: u64,
+        phase: crate::state::HotstuffPhase,
+    },
 }
 
 #[cw_serde]
@@ -121,36 +542,376 @@ pub enum QueryMsg {
     Issuers {
         start_after: Option<String>,
         limit: Option<u32>,
+        /// Include issuers whose authorization has expired. Defaults to
+        /// false, matching CW721's `include_expired` convention.
+        #[serde(default)]
+        include_expired: bool,
     },
-    
+
     /// Get issuer information
     #[returns(IssuerResponse)]
-    Issuer { address: String },
-    
+    Issuer {
+        address: String,
+        /// Return the issuer even if its authorization has expired.
+        #[serde(default)]
+        include_expired: bool,
+    },
+
+    /// Get an address's escrowed `Config::issuer_bond`, if any. Present for
+    /// active issuers and for removed issuers that haven't yet called
+    /// `ExecuteMsg::WithdrawBond`.
+    #[returns(Option<crate::state::IssuerBond>)]
+    IssuerBond { address: String },
+
+    /// Get an address's current `Config::rate_limit` token-bucket state.
+    /// `None` means it has never submitted a proof since a limit was
+    /// configured, i.e. its bucket is still full.
+    #[returns(Option<crate::state::RateLimitBucket>)]
+    RateLimitBucket { address: String },
+
+    /// Get a circuit creator's reputation tally and derived local trust
+    /// score - see `crate::state::ReputationTally` for why this is a
+    /// single-hop score rather than a full EigenTrust power-iteration
+    /// result.
+    #[returns(IssuerReputationResponse)]
+    IssuerReputation { address: String },
+
+    /// Materialized view of every non-revoked attestation issued to
+    /// `subject_did`, combining `DID_ATTESTATIONS`'s entries for that
+    /// subject into a single response - the scoped-down analogue of the
+    /// requested "linear-combine pass that merges multiple attestation
+    /// sources into a single materialized view per DID".
+    #[returns(DidCredentialViewResponse)]
+    DidCredentialView {
+        subject_did: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// Page through every attestation `issuer_did` has emitted, via
+    /// `ISSUER_DID_ATTESTATIONS`'s prefix scan.
+    #[returns(Vec<String>)]
+    DidAttestationsByIssuer {
+        issuer_did: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// Page through `DID_PROPAGATION_LOG` in sequence order - a
+    /// federation node's poll-based substitute for subscribing to a
+    /// gossiped credential/revocation event stream. See
+    /// `crate::state::DidPropagationEvent`.
+    #[returns(Vec<crate::state::DidPropagationEvent>)]
+    DidPropagationEvents {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+
+    /// Get the weighted quorum-certificate state for `(seq, phase)`.
+    #[returns(Option<crate::state::FinalityCertificate>)]
+    FinalityCertificate {
+        seq: u64,
+        phase: crate::state::HotstuffPhase,
+    },
+
+    /// Count of `DID_PROPAGATION_LOG` entries finalized so far - seqs
+    /// `0..finalized_seq` are final. See `crate::state::FINALIZED_SEQ`.
+    #[returns(u64)]
+    FinalizedSeq {},
+
+    /// Get the guardian set most recently registered via
+    /// `ExecuteMsg::RegisterGuardianSet`.
+    #[returns(Option<crate::state::GuardianSet>)]
+    CurrentGuardianSet {},
+
+    /// Get a specific guardian set by index, current or retired.
+    #[returns(Option<crate::state::GuardianSet>)]
+    GuardianSet { index: u32 },
+
+    /// Check whether `SubmitAttestedProof` has already ingested this
+    /// `(emitter_chain, emitter_address, sequence)` attestation.
+    #[returns(bool)]
+    AttestationProcessed {
+        emitter_chain: u32,
+        emitter_address: String,
+        sequence: u64,
+    },
+
+    /// Get a cross-chain transaction relayed via
+    /// `ExecuteMsg::SubmitCrossChainTransaction`, by `tx_id`.
+    #[returns(Option<crate::state::CrossChainTx>)]
+    CrossChainTransaction { tx_id: u64 },
+
+    /// Current alpha-beta-filtered gas-price estimate for `denom`.
+    #[returns(Option<crate::state::FilterEstimate>)]
+    GasPriceEstimate { denom: String },
+
+    /// Current root hash of the sparse Merkle tree `tree_id` (one tree per
+    /// `circuit_id`, populated as nullifiers are spent - see
+    /// `check_and_spend_nullifier`). `Err` if `tree_id` has never had a
+    /// leaf inserted.
+    #[returns(Binary)]
+    MerkleRoot { tree_id: String },
+
+    /// Inclusion proof (sibling hash per level, leaf to root) for the leaf
+    /// at `leaf_index` in `tree_id`. `Err` if `tree_id` is unknown or
+    /// `leaf_index` is at or past that tree's current leaf count.
+    #[returns(MerkleProofResponse)]
+    MerkleInclusionProof { tree_id: String, leaf_index: u64 },
+
+    /// Verify that `leaf` at `leaf_index` is included in `tree_id`'s tree
+    /// under its current root, given a sibling-hash `proof` of the same
+    /// shape `MerkleInclusionProof` returns. Pure check against the
+    /// currently stored root - does not require the leaf to still be the
+    /// most recently inserted one.
+    #[returns(bool)]
+    VerifyMerkleProof { tree_id: String, leaf: Binary, leaf_index: u64, proof: Vec<Binary> },
+
+    /// Every named operational metric currently recorded - see
+    /// `crate::state::METRICS` for where they're emitted from.
+    #[returns(MetricsSnapshotResponse)]
+    MetricsSnapshot {},
+
+    /// Export up to `limit` (default 10, max 100) `AUDIT_LOG` entries in
+    /// structure-of-arrays form, starting after `start_after` (a `seq`).
+    /// Columnar rather than `Vec<AuditEntry>` so a bulk off-chain consumer
+    /// (a columnar store, a spreadsheet) can pull one column at a time
+    /// instead of re-parsing a row object per entry.
+    #[returns(AuditBatchExportResponse)]
+    AuditBatchExport { start_after: Option<u64>, limit: Option<u32> },
+
+    /// Resolve `circuit_type` through `PROOF_SYSTEM_REGISTRY` and return its
+    /// backend's metadata. `Err` if `circuit_type` has no registered alias
+    /// (see `ExecuteMsg::RegisterProofSystem`).
+    #[returns(ProofSystemBackendResponse)]
+    ProofSystemBackend { circuit_type: String },
+
+    /// List every `circuit_type -> ProofSystem` alias currently registered.
+    #[returns(ProofSystemRegistryResponse)]
+    ProofSystemRegistry {},
+
+    /// Epoch number of the currently active `ValidatorSet`, or `0` if no
+    /// `ProposalType::RotateValidators` has ever been promoted.
+    #[returns(u64)]
+    CurrentEpoch {},
+
+    /// Get a specific validator set by epoch, current or historical.
+    #[returns(Option<crate::state::ValidatorSet>)]
+    ValidatorSet { epoch: u64 },
+
+    /// Get the validator set staged by `ProposalType::RotateValidators`,
+    /// waiting for its `activates_at_height`.
+    #[returns(Option<crate::state::PendingValidatorSet>)]
+    PendingValidatorSet {},
+
     /// List governance proposals
     #[returns(ProposalsResponse)]
     Proposals {
         start_after: Option<u64>,
         limit: Option<u32>,
+        /// Defaults to ascending (oldest-first, matching every other
+        /// paginated list query in this contract).
+        #[serde(default)]
+        order: SortOrder,
+        /// Only return proposals in this derived status. Omit to list all.
+        status: Option<ProposalStatus>,
     },
     
     /// Get specific governance proposal
     #[returns(ProposalResponse)]
     Proposal { proposal_id: u64 },
-    
+
+    /// Required co-sponsors and their sign-off status for a proposal, per
+    /// `GovernanceProposal::signatories`.
+    #[returns(Vec<(Addr, bool)>)]
+    SignatoriesByProposal { proposal_id: u64 },
+
+    /// A single voter's ballot on a proposal, if one was cast and not since
+    /// relinquished.
+    #[returns(Option<VoteRecordResponse>)]
+    VoteRecord { proposal_id: u64, voter: String },
+
+    /// Every ballot cast on a proposal, paginated by voter address.
+    #[returns(VotesByProposalResponse)]
+    VotesByProposal {
+        proposal_id: u64,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// A proposal's ordered `ProposalInstruction`s and their executed flags.
+    #[returns(Vec<crate::state::ProposalInstruction>)]
+    ProposalInstructions { proposal_id: u64 },
+
+    /// An account's governance voting weight, falling back to
+    /// `Config::default_voting_power` if never explicitly set.
+    #[returns(u64)]
+    VotingPower { account: String },
+
     /// Check if account has role
     #[returns(bool)]
     HasRole { role: String, account: String },
     
-    /// Get role members
-    #[returns(RoleMembersResponse)]
-    RoleMembers { role: String },
+    /// Get role members, paginated
+    #[returns(Vec<Addr>)]
+    RoleMembers {
+        role: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// Count of members currently holding a role.
+    #[returns(u64)]
+    RoleMemberCount { role: String },
+
+    /// Every role with at least one member.
+    #[returns(Vec<String>)]
+    ListRoles {},
+
+    /// The role currently allowed to grant/revoke `role`.
+    #[returns(String)]
+    RoleAdmin { role: String },
+
+    /// Native tokens collected via `Config::registration_fee`, not yet
+    /// withdrawn by `WithdrawFees`.
+    #[returns(Vec<Coin>)]
+    CollectedFees {},
     
     /// Get timelock transaction
     #[returns(TimelockTransactionResponse)]
     TimelockTransaction { transaction_id: u64 },
+
+    /// Page through the full timelock queue, oldest id first, so operators
+    /// can review everything pending before a delay elapses.
+    #[returns(TimelockTransactionsResponse)]
+    TimelockTransactions {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+        /// Only return transactions in this derived status. Omit to list
+        /// all, matching `QueryMsg::Proposals`'s `status` filter.
+        status: Option<TimelockStatus>,
+    },
+
+    /// Get a circuit's cached `PreparedVerifyingKey`, for debugging cache
+    /// population (`None` until the circuit is registered/migrated under
+    /// the `production-verification` feature).
+    #[returns(PreparedKeyResponse)]
+    GetPreparedKey { circuit_id: String },
+
+    /// Check whether a nullifier has already been spent for a circuit.
+    #[returns(bool)]
+    IsNullifierSpent { circuit_id: String, nullifier: String },
+
+    /// List spent nullifiers for a circuit.
+    #[returns(NullifiersResponse)]
+    ListNullifiersByCircuit {
+        circuit_id: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// Who spent a nullifier and at what block height, for a circuit.
+    /// `None` if it hasn't been spent — the richer sibling of
+    /// `IsNullifierSpent`.
+    #[returns(Option<NullifierStatusResponse>)]
+    NullifierStatus { circuit_id: String, nullifier: String },
+
+    /// Get the current timelock governance configuration, including
+    /// whether it has been permanently frozen.
+    #[returns(GovernanceConfigResponse)]
+    GovernanceConfig {},
+
+    /// A revocable circuit's current non-revocation accumulator: the
+    /// modulus/base/value a holder needs to recompute or refresh their
+    /// witness, and the epoch, which increments on every
+    /// `RevokeCredential`. Errors if the circuit never configured
+    /// `revocation_index`.
+    #[returns(RevocationStateResponse)]
+    RevocationState { circuit_id: String },
+
+    /// A `ProposalType::SelectIssuerCommittee` proposal's outcome: `None`
+    /// if it hasn't executed yet, or has executed but is still waiting on
+    /// `ExecuteMsg::ReceiveRandomness`.
+    #[returns(Option<IssuerCommitteeResponse>)]
+    IssuerCommittee { proposal_id: u64 },
+
+    /// The contract-wide fee `ExecuteMsg::RegisterCircuit` must carry,
+    /// settable via `ExecuteMsg::UpdateFees`.
+    #[returns(FeeConfigResponse)]
+    FeeConfig {},
+
+    /// Authenticate `permit` (which must authorize
+    /// `crate::permit::PermitAction::Query` against this contract and
+    /// recover to the admin or a registered issuer), then answer `query` as
+    /// if it had been sent directly. Lets a relayer run gated queries on an
+    /// issuer's behalf without that issuer holding a tx-signing key, the
+    /// query-side counterpart to `ExecuteMsg::SubmitProofWithPermit`.
+    #[returns(Binary)]
+    WithPermit {
+        permit: crate::permit::Permit,
+        query: Box<QueryMsg>,
+    },
+}
+
+#[cw_serde]
+pub struct GovernanceConfigResponse {
+    pub timelock_enabled: bool,
+    pub min_timelock_delay: u64,
+    pub multisig_config: Option<MultisigConfig>,
+    pub frozen: bool,
+    /// Deposit `SubmitGovernanceProposal` must escrow, and its refund
+    /// policy. `None` means proposals are free.
+    pub proposal_deposit: Option<ProposalDepositConfig>,
+    /// Address trusted to deliver beacons for `SelectIssuerCommittee`
+    /// proposals via `ExecuteMsg::ReceiveRandomness`. `None` means that
+    /// proposal type can't be executed.
+    pub randomness_provider: Option<Addr>,
+}
+
+#[cw_serde]
+pub struct NullifiersResponse {
+    pub nullifiers: Vec<String>,
+}
+
+#[cw_serde]
+pub struct NullifierStatusResponse {
+    pub circuit_id: String,
+    pub submitter: Addr,
+    pub spent_at_height: u64,
+}
+
+#[cw_serde]
+pub struct RevocationStateResponse {
+    pub circuit_id: String,
+    pub modulus: String,
+    pub base: String,
+    pub value: String,
+    pub epoch: u64,
+}
+
+#[cw_serde]
+pub struct IssuerCommitteeResponse {
+    pub candidates: Vec<Addr>,
+    pub k: u32,
+    pub beacon: Binary,
+    pub committee: Vec<Addr>,
+    pub fulfilled_at: u64,
+}
+
+#[cw_serde]
+pub struct FeeConfigResponse {
+    pub registration_fee: Option<Coin>,
+}
+
+#[cw_serde]
+pub struct PreparedKeyResponse {
+    pub circuit_id: String,
+    pub prepared_verifying_key: Option<String>,
 }
 
+#[cw_serde]
+pub struct MigrateMsg {}
+
 #[cw_serde]
 pub struct CircuitResponse {
     pub circuit_id: String,
@@ -159,6 +920,7 @@ pub struct CircuitResponse {
     pub creator: Addr,
     pub active: bool,
     pub created_at: u64,
+    pub proof_system: ProofSystem,
 }
 
 #[cw_serde]
@@ -166,6 +928,69 @@ pub struct CircuitsResponse {
     pub circuits: Vec<CircuitResponse>,
 }
 
+/// Response for `QueryMsg::MerkleInclusionProof` — sibling hashes from the
+/// leaf's level up to (but not including) the root, in that order, plus
+/// the leaf value itself so the caller can feed both straight into
+/// `QueryMsg::VerifyMerkleProof`.
+#[cw_serde]
+pub struct MerkleProofResponse {
+    pub leaf: Binary,
+    pub siblings: Vec<Binary>,
+}
+
+/// Response for `QueryMsg::MetricsSnapshot`.
+#[cw_serde]
+pub struct MetricsSnapshotResponse {
+    pub metrics: Vec<(String, crate::state::Metric)>,
+}
+
+/// Response for `QueryMsg::IssuerReputation`.
+#[cw_serde]
+pub struct IssuerReputationResponse {
+    pub tally: crate::state::ReputationTally,
+    /// `None` if `tally` has no recorded outcomes yet.
+    pub trust_score: Option<Decimal>,
+}
+
+/// Response for `QueryMsg::DidCredentialView`.
+#[cw_serde]
+pub struct DidCredentialViewResponse {
+    pub subject_did: String,
+    pub attestations: Vec<crate::state::DidAttestation>,
+}
+
+/// Response for `QueryMsg::AuditBatchExport` - one `AuditEntry` field per
+/// `Vec`, all the same length and index-aligned, instead of a
+/// `Vec<AuditEntry>`.
+#[cw_serde]
+pub struct AuditBatchExportResponse {
+    pub seqs: Vec<u64>,
+    pub actions: Vec<String>,
+    pub actors: Vec<Addr>,
+    pub circuit_ids: Vec<String>,
+    pub successes: Vec<bool>,
+    pub timestamps: Vec<u64>,
+}
+
+/// Response for `QueryMsg::ProofSystemBackend` — the
+/// `ProofSystemBackend` trait's metadata methods for the backend a
+/// `circuit_type` alias resolves to.
+#[cw_serde]
+pub struct ProofSystemBackendResponse {
+    pub circuit_type: String,
+    pub backend: ProofSystem,
+    pub gas_estimate: u64,
+    pub security_level: u32,
+    pub supported_features: Vec<String>,
+    pub max_public_inputs: u32,
+}
+
+/// Response for `QueryMsg::ProofSystemRegistry`.
+#[cw_serde]
+pub struct ProofSystemRegistryResponse {
+    pub entries: Vec<(String, ProofSystem)>,
+}
+
 #[cw_serde]
 pub struct ProofResponse {
     pub proof_id: String,
@@ -192,6 +1017,15 @@ pub struct ContractInfoResponse {
     pub governance_enabled: bool,
     pub dao_address: Option<Addr>,
     pub total_issuers: u64,
+    /// Number of `SubmitProofBatch` messages that have successfully
+    /// verified (see `Config::total_proof_batches`).
+    pub total_proof_batches: u64,
+    pub default_quorum_threshold: u64,
+    pub default_pass_threshold: u64,
+    pub default_quorum_fraction: Decimal,
+    pub default_threshold_fraction: Decimal,
+    pub voting_period_seconds: u64,
+    pub min_voting_period_seconds: u64,
 }
 
 #[cw_serde]
@@ -201,6 +1035,7 @@ pub struct IssuerResponse {
     pub active: bool,
     pub added_by: Addr,
     pub added_at: u64,
+    pub expires_at: Option<u64>,
 }
 
 #[cw_serde]
@@ -220,6 +1055,44 @@ pub struct ProposalResponse {
     pub executed: bool,
     pub votes_for: u64,
     pub votes_against: u64,
+    /// Stake-weighted sum of `VOTING_POWER` for every `VoteChoice::Abstain`
+    /// voter. Counts toward `quorum_met` but not `threshold_met`.
+    pub votes_abstain: u64,
+    pub quorum_threshold: u64,
+    pub pass_threshold: u64,
+    /// Minimum participation fraction of `total_eligible_weight`, an
+    /// additional gate alongside `quorum_threshold`.
+    pub quorum_fraction: Decimal,
+    /// Minimum Yes fraction of `votes_for + votes_against`, an additional
+    /// gate alongside `pass_threshold`.
+    pub approval_threshold: Decimal,
+    pub total_eligible_weight: u64,
+    pub scheduled_transaction_id: Option<u64>,
+    /// `votes_for + votes_against + votes_abstain >= quorum_threshold`
+    /// AND the `quorum_fraction` gate, regardless of whether voting has
+    /// closed yet.
+    pub quorum_met: bool,
+    /// `votes_for >= pass_threshold` AND the `approval_threshold` gate,
+    /// regardless of whether voting has closed yet.
+    pub threshold_met: bool,
+    /// Derived lifecycle status; see `ProposalStatus`.
+    pub status: ProposalStatus,
+    /// Funds escrowed by `proposer` at submission time, per
+    /// `Config::proposal_deposit`. `None` if proposals were free then.
+    pub deposit: Option<Coin>,
+    /// Whether `deposit` has already been returned to `proposer`.
+    pub deposit_refunded: bool,
+    /// Distinct multisig safety-council signers that have approved this
+    /// proposal so far. Empty and irrelevant when `Config::multisig_config`
+    /// isn't enabled.
+    pub approvals: Vec<Addr>,
+    /// `Config::multisig_config`'s threshold minus `approvals.len()`,
+    /// floored at zero. `0` when multisig isn't enabled.
+    pub remaining_approvals: u64,
+    /// Required co-sponsors and whether each has signed off yet, per
+    /// `GovernanceProposal::signatories`. Empty when the proposal skipped
+    /// `ProposalStatus::Draft`.
+    pub signatories: Vec<(Addr, bool)>,
 }
 
 #[cw_serde]
@@ -227,21 +1100,49 @@ pub struct ProposalsResponse {
     pub proposals: Vec<ProposalResponse>,
 }
 
+/// A single voter's ballot, flattening `state::VoteRecord` with the voter's
+/// address for query responses.
+#[cw_serde]
+pub struct VoteRecordResponse {
+    pub voter: Addr,
+    pub choice: VoteChoice,
+    pub weight: u64,
+    pub voted_at: u64,
+}
+
 #[cw_serde]
-pub struct RoleMembersResponse {
-    pub role: String,
-    pub members: Vec<Addr>,
+pub struct VotesByProposalResponse {
+    pub votes: Vec<VoteRecordResponse>,
 }
 
 #[cw_serde]
 pub struct TimelockTransactionResponse {
     pub id: u64,
     pub proposer: Addr,
-    pub target_function: String,
-    pub params: String,
+    pub msgs: Vec<CosmosMsg>,
     pub scheduled_time: u64,
     pub executed: bool,
     pub cancelled: bool,
     pub approvals: Vec<Addr>,
     pub created_at: u64,
+    pub executors: Vec<Addr>,
+    pub grace_period: Option<u64>,
+    pub status: TimelockStatus,
+}
+
+/// One row of `QueryMsg::TimelockTransactions`'s paginated listing, kept
+/// lightweight (no `msgs`/`proposer`/`executors`) so an operator can page
+/// through the full queue cheaply; `TimelockTransaction { transaction_id }`
+/// still returns the complete record for a single id.
+#[cw_serde]
+pub struct TimelockTransactionSummary {
+    pub id: u64,
+    pub status: TimelockStatus,
+    pub scheduled_time: u64,
+    pub approvals_count: u64,
+}
+
+#[cw_serde]
+pub struct TimelockTransactionsResponse {
+    pub transactions: Vec<TimelockTransactionSummary>,
 }
\ No newline at end of file
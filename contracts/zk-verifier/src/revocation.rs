@@ -0,0 +1,141 @@
+/// RSA accumulator over a revocable circuit's active credential indices.
+///
+/// Each credential index `i` is assigned a deterministic "prime-like"
+/// exponent `member_exponent(i)` (this crate verifies proofs at demo scale
+/// rather than running a real primality test over a 256-bit integer
+/// on-chain, the same tradeoff `verifier::verify_proof` documents for
+/// Groth16 verification). Enrolling a new member folds its exponent into
+/// the accumulator with a single `mod_exp` — no trapdoor required. Removing
+/// one (revocation) does need a full rebuild from the surviving member set,
+/// since this contract deliberately doesn't hold the modulus's
+/// factorization (the trapdoor a real RSA accumulator manager would use for
+/// O(1) removal) — anyone who did would be able to forge membership.
+///
+/// A non-revocation witness for member `i` is the standard accumulator
+/// check: `witness^{member_exponent(i)} == accumulator (mod modulus)`.
+/// Holders compute/refresh their own witness off-chain from
+/// `QueryMsg::RevocationState`; `contract::check_and_verify_revocation`
+/// only verifies.
+use sha2::{Digest, Sha256};
+use cosmwasm_std::Uint256;
+
+use crate::error::ContractError;
+use crate::determinism_audit::mod_exp;
+
+/// Derive a per-circuit modulus/base value, kept to ~120 bits so
+/// `mod_exp`'s checked-multiply squaring step (`x * x`, with `x < modulus`)
+/// can't overflow the 256-bit accumulator.
+fn derive_uint256(label: &str, circuit_id: &str) -> Uint256 {
+    let mut hasher = Sha256::new();
+    hasher.update(label.as_bytes());
+    hasher.update(b":");
+    hasher.update(circuit_id.as_bytes());
+    let digest = hasher.finalize();
+
+    let mut low16 = [0u8; 16];
+    low16.copy_from_slice(&digest[0..16]);
+    let mut n = u128::from_be_bytes(low16);
+    n &= (1u128 << 120) - 1;
+    n |= 1u128 << 119;
+    n |= 1;
+    Uint256::from(n)
+}
+
+/// Deterministic per-circuit modulus for a freshly configured revocable
+/// circuit's accumulator.
+pub fn initial_modulus(circuit_id: &str) -> Uint256 {
+    derive_uint256("zk-verifier/revocation-accumulator/modulus", circuit_id)
+}
+
+/// Deterministic per-circuit base (the accumulator's value with zero
+/// members enrolled) for a freshly configured revocable circuit.
+pub fn initial_base(circuit_id: &str) -> Uint256 {
+    derive_uint256("zk-verifier/revocation-accumulator/base", circuit_id)
+}
+
+/// Derive credential `index`'s accumulator exponent, the role a real RSA
+/// accumulator gives each member's own prime.
+pub fn member_exponent(index: u32) -> Uint256 {
+    let mut hasher = Sha256::new();
+    hasher.update(b"zk-verifier/revocation-accumulator/member");
+    hasher.update(index.to_be_bytes());
+    let mut digest: [u8; 32] = hasher.finalize().into();
+    digest[0] |= 0x80;
+    digest[31] |= 0x01;
+    Uint256::new(digest)
+}
+
+/// Fold `index` into `accumulator`, enrolling it as a new active member.
+pub fn enroll(accumulator: Uint256, modulus: Uint256, index: u32) -> Result<Uint256, ContractError> {
+    mod_exp(accumulator, member_exponent(index), modulus)
+}
+
+/// Rebuild the accumulator value from scratch over `active_indices`,
+/// starting from `base`. Used by `RevokeCredential` since removing a
+/// member can't be done in O(1) without the modulus's factorization.
+pub fn rebuild(base: Uint256, modulus: Uint256, active_indices: &[u32]) -> Result<Uint256, ContractError> {
+    let mut value = base;
+    for &index in active_indices {
+        value = enroll(value, modulus, index)?;
+    }
+    Ok(value)
+}
+
+/// Verify that `witness` proves `index`'s membership in `accumulator`:
+/// `witness^{member_exponent(index)} == accumulator (mod modulus)`.
+pub fn verify_membership(
+    witness: Uint256,
+    index: u32,
+    modulus: Uint256,
+    accumulator: Uint256,
+) -> Result<bool, ContractError> {
+    Ok(mod_exp(witness, member_exponent(index), modulus)? == accumulator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enroll_then_verify_membership_succeeds() {
+        let modulus = initial_modulus("circuit-a");
+        let base = initial_base("circuit-a");
+
+        let after_first = enroll(base, modulus, 1).unwrap();
+        // The witness for the very first enrolled member is the
+        // pre-enrollment accumulator value (here, `base` itself).
+        assert!(verify_membership(base, 1, modulus, after_first).unwrap());
+    }
+
+    #[test]
+    fn verify_membership_rejects_wrong_index() {
+        let modulus = initial_modulus("circuit-a");
+        let base = initial_base("circuit-a");
+
+        let after_first = enroll(base, modulus, 1).unwrap();
+        assert!(!verify_membership(base, 2, modulus, after_first).unwrap());
+    }
+
+    #[test]
+    fn rebuild_excludes_revoked_member() {
+        let modulus = initial_modulus("circuit-a");
+        let base = initial_base("circuit-a");
+
+        let after_first = enroll(base, modulus, 1).unwrap();
+        let after_second = enroll(after_first, modulus, 2).unwrap();
+
+        // Member 2's witness against the two-member accumulator is the
+        // pre-its-own-enrollment value.
+        assert!(verify_membership(after_first, 2, modulus, after_second).unwrap());
+
+        // Revoking member 1 rebuilds from just member 2; member 2's old
+        // witness (valid before the rebuild) no longer verifies, because
+        // the accumulator itself changed.
+        let rebuilt = rebuild(base, modulus, &[2]).unwrap();
+        assert_ne!(rebuilt, after_second);
+        assert!(!verify_membership(after_first, 2, modulus, rebuilt).unwrap());
+        // The correct post-rebuild witness for member 2 (no other active
+        // members) is `base` again.
+        assert!(verify_membership(base, 2, modulus, rebuilt).unwrap());
+    }
+}
@@ -1,5 +1,5 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Binary, Coin, CosmosMsg, Decimal, Uint128};
 use cw_storage_plus::{Item, Map};
 
 #[cw_serde]
@@ -12,6 +12,445 @@ pub struct Config {
     pub multisig_config: Option<MultisigConfig>,
     pub timelock_enabled: bool,
     pub min_timelock_delay: u64, // seconds
+    /// Set by `ExecuteMsg::FreezeTimelock`; once `true`, the governance
+    /// configuration (`min_timelock_delay`, `multisig_config`, and
+    /// proposer/executor role grants) is permanently immutable.
+    #[serde(default)]
+    pub frozen: bool,
+    /// Native-token fee `RegisterCircuit` must carry in `info.funds`.
+    /// `None` means registration is free, same as before this field existed.
+    #[serde(default)]
+    pub registration_fee: Option<Coin>,
+    /// Number of `ExecuteMsg::SubmitProofBatch` messages that have
+    /// successfully verified, distinct from `total_proofs` which counts
+    /// individual proofs across both single and batched submission.
+    #[serde(default)]
+    pub total_proof_batches: u64,
+    /// Fallback weight for a voter with no `VOTING_POWER` entry. Defaults
+    /// to 1, preserving one-account-one-vote until weights are assigned.
+    #[serde(default = "default_voting_power")]
+    pub default_voting_power: u64,
+    /// Minimum total participating weight (`votes_for + votes_against +
+    /// votes_abstain`) copied onto new `GovernanceProposal`s, below which execution is
+    /// rejected with `ContractError::QuorumNotReached`.
+    #[serde(default)]
+    pub default_quorum_threshold: u64,
+    /// Minimum `votes_for` copied onto new `GovernanceProposal`s, required
+    /// (once quorum is met) for execution to proceed.
+    #[serde(default = "default_pass_threshold")]
+    pub default_pass_threshold: u64,
+    /// Minimum fraction of `GovernanceProposal::total_eligible_weight` that
+    /// must have participated (`votes_for + votes_against + votes_abstain`)
+    /// copied onto new proposals as `quorum_fraction`. An additional
+    /// AND-gate alongside `default_quorum_threshold`'s absolute weight
+    /// check, the same composition `Config::multisig_config` already
+    /// layers on top of the DAO vote tally. Defaults to zero, trivially
+    /// satisfied, so this gate is a no-op until explicitly configured.
+    #[serde(default)]
+    pub default_quorum_fraction: Decimal,
+    /// Minimum fraction of `votes_for` out of `votes_for + votes_against`
+    /// (abstains excluded) copied onto new proposals as
+    /// `approval_threshold`. An additional AND-gate alongside
+    /// `default_pass_threshold`'s absolute weight check. Defaults to zero,
+    /// trivially satisfied, so this gate is a no-op until explicitly
+    /// configured.
+    #[serde(default)]
+    pub default_threshold_fraction: Decimal,
+    /// Fallback `grace_period` for `ScheduleTimelockTransaction` calls that
+    /// don't specify one. `None` preserves the old behavior of never
+    /// expiring by default.
+    #[serde(default)]
+    pub default_timelock_grace_period: Option<u64>,
+    /// Default `GovernanceProposal::voting_end` window from submission,
+    /// used when `SubmitGovernanceProposal` doesn't override it. Also the
+    /// ceiling an override may not exceed; see `min_voting_period_seconds`
+    /// for the floor.
+    #[serde(default = "default_voting_period_seconds")]
+    pub voting_period_seconds: u64,
+    /// Floor on a per-proposal `voting_period` override, so a proposer
+    /// can't shrink the review window down to nothing.
+    #[serde(default = "default_min_voting_period_seconds")]
+    pub min_voting_period_seconds: u64,
+    /// Pre-propose deposit `SubmitGovernanceProposal` must escrow to deter
+    /// spam proposals, DAO-DAO-style. `None` means proposals are free, same
+    /// as before this field existed.
+    #[serde(default)]
+    pub proposal_deposit: Option<ProposalDepositConfig>,
+    /// Address whose `ExecuteMsg::ReceiveRandomness` calls are trusted to
+    /// deliver the drand-style beacon a `ProposalType::SelectIssuerCommittee`
+    /// proposal is waiting on. `None` means that proposal type can never be
+    /// executed, since there's nowhere to request the beacon from.
+    #[serde(default)]
+    pub randomness_provider: Option<Addr>,
+    /// Bond `ExecuteMsg::AddIssuer` must escrow in `info.funds`, and the
+    /// slashing/withdrawal policy over that escrow. `None` means issuer
+    /// onboarding is unbonded, same as before this field existed.
+    #[serde(default)]
+    pub issuer_bond: Option<IssuerBondConfig>,
+    /// Per-submitter token-bucket limit on `ExecuteMsg::SubmitProof`.
+    /// `None` means submissions are unlimited, same as before this field
+    /// existed.
+    #[serde(default)]
+    pub rate_limit: Option<RateLimitConfig>,
+}
+
+/// Token-bucket policy applied per proof submitter, configured once via
+/// `Config::rate_limit`.
+#[cw_serde]
+pub struct RateLimitConfig {
+    /// Maximum tokens a `RATE_LIMIT_BUCKETS` entry can hold at once, i.e.
+    /// the size of a submission burst a submitter can spend before being
+    /// throttled down to the refill rate.
+    pub capacity: u64,
+    /// Tokens restored per second since a bucket's `last_refill`, capped
+    /// at `capacity`.
+    pub refill_per_second: u64,
+}
+
+/// A submitter's current token-bucket state. Refilled lazily (on the next
+/// `SubmitProof` from that submitter) rather than on a timer, the same
+/// lazy-update approach `GuardianSet` expiration already uses.
+#[cw_serde]
+pub struct RateLimitBucket {
+    pub tokens: u64,
+    pub last_refill: u64,
+}
+
+/// Per-submitter token buckets enforcing `Config::rate_limit`. Missing
+/// means that submitter has never been throttled, i.e. a full bucket.
+pub const RATE_LIMIT_BUCKETS: Map<&str, RateLimitBucket> = Map::new("rate_limit_buckets");
+
+/// A circuit creator's local trust tally: how many proofs submitted
+/// against their circuits verified (`satisfactory`) versus failed
+/// (`unsatisfactory`). This is the local-trust input `c_ij` an EigenTrust
+/// global score would normally aggregate over a full peer graph via power
+/// iteration; this contract has no off-chain process to drive that
+/// iteration and no gas budget to run it per-transaction, so
+/// `query_issuer_reputation` (contract.rs) computes a single-hop score
+/// directly from this tally instead of a network-wide eigenvector.
+#[cw_serde]
+pub struct ReputationTally {
+    pub satisfactory: u64,
+    pub unsatisfactory: u64,
+}
+
+/// Reputation tallies keyed by circuit creator address (`Circuit::creator`)
+/// - the issuer whose circuits are being vouched for or against by each
+/// proof submitted against them. Updated by `submit_proof_as` alongside
+/// `check_and_spend_nullifier`.
+pub const REPUTATION_TALLIES: Map<&str, ReputationTally> = Map::new("reputation_tallies");
+
+/// A credential attestation issued by one DID about another, the unit
+/// this contract's DID-keyed attestation store persists. Scoped down
+/// from the requested column-family store: CosmWasm has one backing KV
+/// store with no RocksDB-style column families to isolate compaction or
+/// retention policy by, so the isolation that actually matters here
+/// (cheap per-category range scans) comes from keying on canonical UTF-8
+/// DID strings rather than from separate physical column families — see
+/// `DID_ATTESTATIONS` and `ISSUER_DID_ATTESTATIONS` below.
+#[cw_serde]
+pub struct DidAttestation {
+    pub issuer_did: String,
+    /// The on-chain signer that called `IssueDidAttestation`, kept
+    /// alongside `issuer_did` since the DID string itself is a
+    /// caller-supplied identifier with no binding to chain identity -
+    /// `RevokeDidAttestation` authorizes against this, not `issuer_did`.
+    pub issuer_addr: Addr,
+    pub subject_did: String,
+    pub attestation_type: String,
+    pub data: Binary,
+    pub issued_at: u64,
+    pub revoked: bool,
+}
+
+/// Attestations keyed by `(subject_did, attestation_id)`, so
+/// `DID_ATTESTATIONS.prefix(subject_did)` is a cheap range scan over
+/// everything issued to one subject - the "subjects" and "attestations"
+/// column families collapsed into one canonically-keyed `Map`.
+pub const DID_ATTESTATIONS: Map<(&str, &str), DidAttestation> = Map::new("did_attestations");
+
+/// Secondary index keyed by `(issuer_did, attestation_id)`, mirroring the
+/// `ROLE_MEMBERS`/`CIRCUIT_PROOFS` secondary-index-as-bool-map pattern
+/// already used elsewhere in this file, so
+/// `ISSUER_DID_ATTESTATIONS.prefix(issuer_did)` is a cheap range scan
+/// over everything one issuer emitted - the "issuers" column family.
+pub const ISSUER_DID_ATTESTATIONS: Map<(&str, &str), bool> = Map::new("issuer_did_attestations");
+
+/// Monotonic counter handing out `attn_<seq>` attestation ids, the same
+/// O(1)-allocation approach `AUDIT_SEQ` uses for audit log entries.
+pub const DID_ATTESTATION_SEQ: Item<u64> = Item::new("did_attestation_seq");
+
+/// Which `DID_PROPAGATION_LOG` event kind occurred.
+#[cw_serde]
+pub enum DidPropagationEventKind {
+    AttestationIssued,
+    AttestationRevoked,
+}
+
+/// One entry in the federation's credential/revocation propagation feed.
+/// A libp2p gossip swarm has no role in this contract - a CosmWasm
+/// contract has no raw sockets, no async IO, and no peer identity beyond
+/// its own chain address, so it cannot run `NetworkBehaviour` or publish
+/// to a `Swarm`. What it already provides instead is the thing gossip is
+/// *for*: a single globally-ordered, agreed-upon log every federation
+/// node can read. `IssueDidAttestation`/`RevokeDidAttestation` are
+/// `publish_attestation`, `QueryMsg::DidCredentialView` is
+/// `request_status(did)`, and this log, polled via
+/// `QueryMsg::DidPropagationEvents`, is the "subscription stream of
+/// inbound events" - nodes converge by polling consensus-ordered state
+/// instead of relaying gossip messages to each other.
+#[cw_serde]
+pub struct DidPropagationEvent {
+    pub seq: u64,
+    pub kind: DidPropagationEventKind,
+    pub issuer_did: String,
+    pub subject_did: String,
+    pub attestation_id: String,
+    pub timestamp: u64,
+}
+
+/// O(1)-allocation sequence counter for `DID_PROPAGATION_LOG`, the same
+/// approach `AUDIT_SEQ` uses.
+pub const DID_PROPAGATION_SEQ: Item<u64> = Item::new("did_propagation_seq");
+
+/// Append-only propagation feed, the federation-wide replacement for a
+/// gossiped event stream - see `DidPropagationEvent`.
+pub const DID_PROPAGATION_LOG: Map<u64, DidPropagationEvent> = Map::new("did_propagation_log");
+
+/// One phase of the three-chain HotStuff commit rule a `DID_PROPAGATION_LOG`
+/// sequence number passes through on its way to being finalized.
+#[cw_serde]
+pub enum HotstuffPhase {
+    Prepare,
+    PreCommit,
+    Commit,
+}
+
+impl HotstuffPhase {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HotstuffPhase::Prepare => "prepare",
+            HotstuffPhase::PreCommit => "pre_commit",
+            HotstuffPhase::Commit => "commit",
+        }
+    }
+}
+
+/// Weighted vote tally for one `(seq, phase)` pair, the on-chain analogue
+/// of a HotStuff quorum certificate. Full HotStuff has a leader propose a
+/// block extending the highest QC, replicas vote, and the leader
+/// aggregates a threshold signature into a QC it broadcasts; this
+/// contract has no leader/pacemaker/view-change machinery (there's
+/// nothing to drive a view timeout - execution only happens when
+/// `VoteFinality` is called) and no BLS threshold signatures, so a QC
+/// here is just this weighted sum crossing `quorum_weight`, reusing
+/// `VALIDATOR_SETS`/`voting_power`/`total_eligible_weight_at` (the same
+/// weighted roster `GovernanceProposal` tallies against) instead of a
+/// fresh committee abstraction.
+#[cw_serde]
+pub struct FinalityCertificate {
+    pub seq: u64,
+    pub phase: HotstuffPhase,
+    pub weight: u64,
+    pub quorum_weight: u64,
+    pub quorum_met: bool,
+}
+
+/// Keyed by `(seq, phase.as_str())`.
+pub const FINALITY_CERTIFICATES: Map<(u64, &str), FinalityCertificate> = Map::new("finality_certificates");
+
+/// Records that `voter` already voted for `(seq, phase)`, rejecting a
+/// double-vote the same way `VOTERS` does for governance proposals.
+/// Keyed by `(seq, phase.as_str(), voter)`.
+pub const FINALITY_VOTERS: Map<(u64, &str, &str), bool> = Map::new("finality_voters");
+
+/// Count of `DID_PROPAGATION_LOG` entries finalized so far - seqs
+/// `0..FINALIZED_SEQ` are final, the same next-free-slot convention
+/// `AUDIT_SEQ` uses rather than storing the last finalized seq directly,
+/// which would be ambiguous between "seq 0 is final" and "nothing is
+/// final yet". Because the log is a single linear sequence (not a tree
+/// of candidate blocks), committing `seq` at the `Commit` phase
+/// finalizes every ancestor up to it as a side effect - the
+/// simplification the three-chain rule collapses to once there's no
+/// fork to choose between.
+pub const FINALIZED_SEQ: Item<u64> = Item::new("finalized_seq");
+
+/// Bonding/slashing policy for issuer onboarding, configured once via
+/// `Config::issuer_bond`.
+#[cw_serde]
+pub struct IssuerBondConfig {
+    /// Native-token amount `AddIssuer` must carry in `info.funds`, escrowed
+    /// per-issuer in `ISSUER_BONDS`.
+    pub bond: Coin,
+    /// Basis points (of 10_000) of an issuer's remaining `ISSUER_BONDS`
+    /// entry slashed to `COLLECTED_FEES` each time one of its proof
+    /// submissions is recorded `verified: false`. 0 disables slashing.
+    #[serde(default)]
+    pub slash_bps: u64,
+    /// Seconds an issuer must wait after `RemoveIssuer` before
+    /// `WithdrawBond` returns its remaining bond. 0 means immediately
+    /// withdrawable.
+    #[serde(default)]
+    pub withdrawal_delay: u64,
+}
+
+/// Escrowed `Config::issuer_bond` for an address that currently has (or
+/// had) an `Issuer` registration. Keyed independently of `ISSUERS` so the
+/// bond survives `RemoveIssuer` until `WithdrawBond` claims it back.
+#[cw_serde]
+pub struct IssuerBond {
+    pub amount: Coin,
+    /// Block time after which `WithdrawBond` will return `amount`. `None`
+    /// while the issuer is still active, since an active issuer's bond
+    /// isn't withdrawable; set by `RemoveIssuer` to
+    /// `now + IssuerBondConfig::withdrawal_delay`.
+    #[serde(default)]
+    pub withdrawable_at: Option<u64>,
+}
+
+/// Escrowed `Config::issuer_bond` amounts, keyed by issuer address.
+pub const ISSUER_BONDS: Map<&str, IssuerBond> = Map::new("issuer_bonds");
+
+/// A quorum-based guardian set for cross-chain proof attestations,
+/// Wormhole-style. `pubkeys[i]` is the secp256k1 public key
+/// `GuardianSignature { pubkey_index: i, .. }` must verify against.
+#[cw_serde]
+pub struct GuardianSet {
+    pub index: u32,
+    pub pubkeys: Vec<Binary>,
+    /// Block time after which this set's signatures are no longer
+    /// accepted. 0 means it never expires, which only ever holds for the
+    /// current set — `ExecuteMsg::RegisterGuardianSet` stamps the
+    /// outgoing set's `expiration_time` to the rotation time.
+    #[serde(default)]
+    pub expiration_time: u64,
+}
+
+/// Registered guardian sets, keyed by `GuardianSet::index`.
+pub const GUARDIAN_SETS: Map<u32, GuardianSet> = Map::new("guardian_sets");
+
+/// Index of the guardian set `ExecuteMsg::RegisterGuardianSet` most
+/// recently registered. Older indices remain valid for attestations signed
+/// while they were current, until `GuardianSet::expiration_time` passes.
+pub const CURRENT_GUARDIAN_SET_INDEX: Item<u32> = Item::new("current_guardian_set_index");
+
+/// One guardian's secp256k1 signature over a [`ProofAttestation::body`]
+/// digest, keyed by its index into the signing `GuardianSet::pubkeys`.
+#[cw_serde]
+pub struct GuardianSignature {
+    pub pubkey_index: u8,
+    pub signature: Binary,
+}
+
+/// Body of a cross-chain proof attestation: a credential proof already
+/// verified on another chain, forwarded here under a guardian quorum
+/// instead of being re-verified locally. `emitter_chain`/`emitter_address`/
+/// `sequence` address it the way a Wormhole VAA addresses a message, and
+/// together key `PROCESSED_ATTESTATIONS` for replay protection.
+#[cw_serde]
+pub struct AttestedProofBody {
+    pub emitter_chain: u32,
+    pub emitter_address: String,
+    pub sequence: u64,
+    pub circuit_id: String,
+    pub public_inputs: Vec<String>,
+    pub verified: bool,
+}
+
+/// A guardian-signed cross-chain proof attestation — the payload of
+/// `ExecuteMsg::SubmitAttestedProof`'s `vaa` field.
+#[cw_serde]
+pub struct ProofAttestation {
+    pub guardian_set_index: u32,
+    pub signatures: Vec<GuardianSignature>,
+    pub body: AttestedProofBody,
+}
+
+/// `(emitter_chain:emitter_address, sequence)` keys of attestations
+/// `SubmitAttestedProof` has already ingested, rejecting replays the same
+/// way `NULLIFIERS` rejects a spent proof nullifier.
+pub const PROCESSED_ATTESTATIONS: Map<(&str, u64), bool> = Map::new("processed_attestations");
+
+/// A guardian-quorum-authorized cross-chain transaction relayed via
+/// `ExecuteMsg::SubmitCrossChainTransaction`: an arbitrary `Vec<CosmosMsg>`
+/// dispatched only once `msgs` carries enough guardian signatures over
+/// `(tx_id, msgs)` to clear quorum against `guardian_set_index`, same as
+/// `ProofAttestation` does for a single proof. Kept around after dispatch
+/// (rather than removed) purely as an executed/replay record.
+#[cw_serde]
+pub struct CrossChainTx {
+    pub tx_id: u64,
+    pub guardian_set_index: u32,
+    pub msgs: Vec<CosmosMsg>,
+    pub executed: bool,
+    pub submitted_at: u64,
+}
+
+/// Cross-chain transactions relayed through `SubmitCrossChainTransaction`,
+/// keyed by the caller-chosen `tx_id` (unique per emitter chain off-chain;
+/// this contract only ever sees one chain's worth, so a bare `u64` key is
+/// enough). `ContractError::CrossChainTxNotFound`/`CrossChainTxAlreadyExecuted`
+/// guard lookups and replays respectively.
+pub const CROSS_CHAIN_TXS: Map<u64, CrossChainTx> = Map::new("cross_chain_txs");
+
+/// An alpha-beta (g-h) filtered smoothed estimate of a noisy point
+/// observation, the same smoothing Filecoin uses for its reward/power
+/// estimates. `position`/`velocity` track the value and its rate of
+/// change in plain integer units (not Q128 fixed-point — this contract
+/// has no existing wide signed-integer type, and the extra precision
+/// isn't needed for a smoothed gas-price hint); `velocity` is signed
+/// because a price trending down is as meaningful as one trending up.
+#[cw_serde]
+pub struct FilterEstimate {
+    pub position: u128,
+    pub velocity: i128,
+    pub last_update: u64,
+}
+
+/// Smoothed gas-price estimates, keyed by fee denom. Updated by
+/// `ExecuteMsg::RecordGasPriceObservation`, `ADMIN_ROLE` only — this
+/// contract has no native gas-price feed of its own to sample, so
+/// observations are pushed in from outside (an oracle relayer or
+/// governance) rather than derived from on-chain data.
+pub const GAS_PRICE_ESTIMATES: Map<&str, FilterEstimate> = Map::new("gas_price_estimates");
+
+/// Deposit `SubmitGovernanceProposal` must attach in `info.funds`, and the
+/// policy governing whether it's later returned to the proposer.
+#[cw_serde]
+pub struct ProposalDepositConfig {
+    pub denom: String,
+    pub amount: Uint128,
+    pub refund_policy: DepositRefundPolicy,
+}
+
+/// Governs whether a `GovernanceProposal::deposit` is returned to its
+/// proposer once voting closes.
+#[cw_serde]
+#[derive(Default)]
+pub enum DepositRefundPolicy {
+    /// Refund only if the proposal met quorum and passed.
+    #[default]
+    OnlyPassed,
+    /// Refund regardless of outcome.
+    Always,
+    /// Never refund; the deposit stays with the contract.
+    Never,
+}
+
+fn default_voting_power() -> u64 {
+    1
+}
+
+fn default_pass_threshold() -> u64 {
+    1
+}
+
+fn default_voting_period_seconds() -> u64 {
+    7 * 24 * 60 * 60
+}
+
+fn default_min_voting_period_seconds() -> u64 {
+    60 * 60
 }
 
 #[cw_serde]
@@ -25,13 +464,53 @@ pub struct MultisigConfig {
 pub struct TimelockTransaction {
     pub id: u64,
     pub proposer: Addr,
-    pub target_function: String,
-    pub params: String, // JSON encoded parameters
+    /// Cross-contract calls dispatched by `execute_timelock_transaction`
+    /// once the delay elapses and multisig threshold (if any) is met.
+    /// Deserializing `ExecuteMsg::ScheduleTimelockTransaction` already
+    /// rejects a malformed message, so a bad payload fails at scheduling
+    /// time rather than silently sitting until the delay expires.
+    pub msgs: Vec<CosmosMsg>,
     pub scheduled_time: u64,
     pub executed: bool,
     pub cancelled: bool,
     pub approvals: Vec<Addr>,
     pub created_at: u64,
+    /// Per-transaction executor allowlist. Empty means any address may
+    /// execute once ripe; otherwise the sender must be listed here or hold
+    /// `access_control::EXECUTOR_ROLE`.
+    #[serde(default)]
+    pub executors: Vec<Addr>,
+    /// Seconds after `scheduled_time` during which the transaction remains
+    /// executable. `None` means it never expires. Checked by
+    /// `execute_timelock_transaction` against `ContractError::TimelockExpired`.
+    #[serde(default)]
+    pub grace_period: Option<u64>,
+    /// Derived lifecycle status, recomputed at every transition
+    /// (`schedule`/`approve`/`execute`/`cancel`) so indexers don't have to
+    /// reconstruct it from `executed`/`cancelled`/`approvals`.
+    #[serde(default)]
+    pub status: TimelockStatus,
+}
+
+/// Lifecycle status of a `TimelockTransaction`, mirrored from its
+/// `executed`/`cancelled`/`approvals` fields for easy off-chain querying.
+#[cw_serde]
+#[derive(Default)]
+pub enum TimelockStatus {
+    #[default]
+    Pending,
+    Approved,
+    /// Query-time-only: `scheduled_time` has passed and no grace period has
+    /// expired, so `execute_timelock_transaction` would currently accept
+    /// this transaction. Never written to storage; see
+    /// `access_control::effective_timelock_status`.
+    Ready,
+    /// Query-time-only: `scheduled_time + grace_period` has passed, so
+    /// `execute_timelock_transaction` now rejects with
+    /// `ContractError::TimelockExpired`. Never written to storage.
+    Expired,
+    Executed,
+    Cancelled,
 }
 
 #[cw_serde]
@@ -48,6 +527,11 @@ pub struct Issuer {
     pub active: bool,
     pub added_by: Addr,
     pub added_at: u64,
+    /// Block time after which this issuer's authorization lapses, rejected
+    /// by `RegisterCircuit` the same way a deactivated issuer is. `None`
+    /// means the authorization never expires on its own.
+    #[serde(default)]
+    pub expires_at: Option<u64>,
 }
 
 #[cw_serde]
@@ -58,6 +542,87 @@ pub struct Circuit {
     pub creator: Addr,
     pub active: bool,
     pub created_at: u64,
+    /// Base64-encoded, arkworks-serialized `PreparedVerifyingKey`, computed
+    /// once at registration time so `SubmitProof` doesn't redo the fixed
+    /// alpha/beta pairing on every call. `None` for circuits registered
+    /// before this field existed, or when built without the
+    /// `production-verification` feature; `migrate` backfills the former.
+    #[serde(default)]
+    pub prepared_verifying_key: Option<String>,
+    /// Index into `public_inputs` carrying this circuit's nullifier, if the
+    /// circuit uses the Orchard-style anti-replay scheme. `None` means
+    /// proofs against this circuit aren't nullifier-checked.
+    #[serde(default)]
+    pub nullifier_index: Option<u32>,
+    /// Poseidon public-input-binding policy, if the circuit requires the
+    /// contract to recompute and check a commitment over its public
+    /// inputs. `None` means proofs against this circuit skip the check.
+    #[serde(default)]
+    pub commitment_policy: Option<CommitmentPolicy>,
+    /// Index into `public_inputs` carrying the credential index a
+    /// revocable circuit's non-revocation witness is about. `None` means
+    /// proofs against this circuit aren't revocation-checked.
+    #[serde(default)]
+    pub revocation_index: Option<u32>,
+    /// Index into `public_inputs` carrying the non-revocation accumulator
+    /// witness paired with `revocation_index`.
+    #[serde(default)]
+    pub revocation_witness_index: Option<u32>,
+    /// Native-token fee `ExecuteMsg::SubmitProof` must carry in
+    /// `info.funds` for this circuit, set via
+    /// `ExecuteMsg::SetCircuitSubmissionFee` (`CIRCUIT_MANAGER_ROLE` only).
+    /// `None` means submission is free, same as before this field existed.
+    /// Collected fees land in `COLLECTED_FEES` alongside registration fees
+    /// and are later distributed by `ExecuteMsg::ClaimRewards`.
+    #[serde(default)]
+    pub submission_fee: Option<Coin>,
+    /// Proving system `verification_key` targets, dispatching `SubmitProof`
+    /// between `verifier::verify_proof` (`Groth16`) and
+    /// `plonk_verifier::verify_plonk_proof` (`Plonk`/`Halo2`). Defaults to
+    /// `Groth16` for circuits registered before this field existed, which
+    /// is what every one of them actually was.
+    #[serde(default)]
+    pub proof_system: ProofSystem,
+}
+
+/// Proving system a `Circuit`'s `verification_key`/submitted proofs use.
+/// `Groth16` reuses `verifier::verify_proof`'s real BN254 pairing check;
+/// `Plonk`/`Halo2` route to `plonk_verifier::verify_plonk_proof` instead
+/// (see that module's doc comment for what it does and doesn't verify).
+#[cw_serde]
+#[derive(Default)]
+pub enum ProofSystem {
+    #[default]
+    Groth16,
+    Plonk,
+    Halo2,
+}
+
+/// Parameters for a BN254 `Fr` Poseidon sponge, mirroring the testudo
+/// `PoseidonTranscript` construction. Stored as decimal/hex `Fr` strings
+/// (the same encoding `parse_public_inputs` already accepts) so the config
+/// round-trips through JSON like every other circuit parameter.
+#[cw_serde]
+pub struct PoseidonParams {
+    pub full_rounds: u32,
+    pub partial_rounds: u32,
+    pub alpha: u64,
+    pub rate: u32,
+    pub capacity: u32,
+    /// `(rate + capacity) x (rate + capacity)` MDS matrix.
+    pub mds: Vec<Vec<String>>,
+    /// `(full_rounds + partial_rounds) x (rate + capacity)` round constants.
+    pub ark: Vec<Vec<String>>,
+}
+
+/// Declares that a circuit wants its `public_inputs` cryptographically
+/// bound into a single on-chain commitment: the ordered public inputs are
+/// absorbed into a Poseidon sponge configured by `poseidon`, one `Fr` is
+/// squeezed out, and it must equal `public_inputs[commitment_index]`.
+#[cw_serde]
+pub struct CommitmentPolicy {
+    pub commitment_index: u32,
+    pub poseidon: PoseidonParams,
 }
 
 #[cw_serde]
@@ -74,15 +639,259 @@ pub struct Proof {
 
 // Storage items
 pub const CONFIG: Item<Config> = Item::new("config");
+/// Internal state-schema version, distinct from the `cw2` contract
+/// name/version: bumped by `contract::migrate` whenever a stored struct
+/// needs a computed backfill that a bare `#[serde(default)]` can't express.
+/// Missing (pre-migration-framework contracts) is treated as version 1.
+pub const STATE_VERSION: Item<u64> = Item::new("state_version");
 pub const CIRCUITS: Map<&str, Circuit> = Map::new("circuits");
 pub const PROOFS: Map<&str, Proof> = Map::new("proofs");
 pub const CIRCUIT_PROOFS: Map<(&str, &str), bool> = Map::new("circuit_proofs"); // (circuit_id, proof_id) -> exists
 pub const ISSUERS: Map<&str, Issuer> = Map::new("issuers"); // address -> issuer info
 pub const GOVERNANCE_PROPOSALS: Map<u64, GovernanceProposal> = Map::new("governance_proposals");
+/// Monotonic counter backing `contract::get_next_proposal_id`, so handing
+/// out a new id is an O(1) load/increment/save instead of a full scan over
+/// `GOVERNANCE_PROPOSALS`. Seeded from the current max id by the
+/// `migrate_seed_proposal_count` migration step for pre-existing
+/// deployments.
+pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
+/// Pending `SelectIssuerCommittee` executions, keyed by proposal id, waiting
+/// on `ExecuteMsg::ReceiveRandomness`.
+pub const RANDOMNESS_REQUESTS: Map<u64, RandomnessRequest> = Map::new("randomness_requests");
+/// Resolved `SelectIssuerCommittee` outcomes, keyed by proposal id.
+pub const ISSUER_COMMITTEES: Map<u64, IssuerCommittee> = Map::new("issuer_committees");
 pub const TIMELOCK_TRANSACTIONS: Map<u64, TimelockTransaction> = Map::new("timelock_transactions");
 pub const ACCESS_CONTROL_ROLES: Map<&str, AccessControlRole> = Map::new("access_control_roles");
 pub const ROLE_MEMBERS: Map<(&str, &str), bool> = Map::new("role_members"); // (role, address) -> bool
-pub const VOTERS: Map<(u64, &str), bool> = Map::new("voters"); // (proposal_id, voter_address) -> has_voted
+
+/// Count of members per role, kept in sync with `ROLE_MEMBERS`/
+/// `AccessControlRole::members` inside `grant_role`/`revoke_role` so
+/// `QueryMsg::RoleMemberCount` doesn't have to scan the member list.
+pub const ROLE_MEMBER_COUNTS: Map<&str, u64> = Map::new("role_member_counts");
+
+/// A ballot cast via `ExecuteMsg::VoteOnProposal`/`ChangeVote`, borrowing
+/// the richer multi-choice design spl-governance uses in place of a bare
+/// yes/no bool. `Abstain` counts toward `GovernanceProposal::quorum_fraction`
+/// participation but not toward `approval_threshold`'s Yes/No ratio.
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub enum VoteChoice {
+    Yes,
+    No,
+    Abstain,
+}
+
+impl VoteChoice {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VoteChoice::Yes => "yes",
+            VoteChoice::No => "no",
+            VoteChoice::Abstain => "abstain",
+        }
+    }
+}
+
+/// A cast ballot, spl-governance's `VoteRecord` account adapted to this
+/// contract's single `Map` entry per `(proposal_id, voter)` instead of a
+/// dedicated account. Persists enough to audit a vote after the fact and to
+/// roll back `GovernanceProposal`'s tally on `ExecuteMsg::RelinquishVote`.
+#[cw_serde]
+pub struct VoteRecord {
+    pub choice: VoteChoice,
+    /// Voting power applied at cast time (`contract::voting_power`,
+    /// pinned to `GovernanceProposal::creation_epoch`), so a later change
+    /// to the voter's weight can't retroactively change a historical tally.
+    pub weight: u64,
+    pub voted_at: u64,
+}
+
+pub const VOTERS: Map<(u64, &str), VoteRecord> = Map::new("voters"); // (proposal_id, voter_address) -> vote record
+
+/// Per-account governance voting power. Missing entries fall back to
+/// `Config::default_voting_power` (1, i.e. one-account-one-vote) so
+/// granting weight is opt-in and existing voters keep working unchanged.
+pub const VOTING_POWER: Map<&str, u64> = Map::new("voting_power");
+
+/// A weighted validator membership snapshot, active for the epoch it's
+/// stored under in `VALIDATOR_SETS`. Mirrors OpenEthereum's validator-set
+/// model: membership and weight only change at an epoch boundary, never
+/// mid-epoch, so every vote cast within an epoch is tallied against the
+/// same roster.
+#[cw_serde]
+pub struct ValidatorSet {
+    pub epoch: u64,
+    pub validators: Vec<(Addr, u64)>,
+    /// Block height this set was promoted from `PENDING_VALIDATOR_SET`.
+    pub activated_at_height: u64,
+}
+
+/// Historical validator sets, keyed by `ValidatorSet::epoch` (epochs start
+/// at `1`; no entry `0` is ever written). A `GovernanceProposal`'s
+/// `creation_epoch` pins which entry its weights and quorum are tallied
+/// against, so a `ProposalType::RotateValidators` landing mid-vote can't
+/// retroactively change an outcome already in progress.
+pub const VALIDATOR_SETS: Map<u64, ValidatorSet> = Map::new("validator_sets");
+
+/// Epoch number of the currently active `VALIDATOR_SETS` entry. Missing
+/// means no rotation has ever been promoted yet, and
+/// `GovernanceProposal::creation_epoch` defaults to `0` to match.
+pub const CURRENT_EPOCH: Item<u64> = Item::new("current_epoch");
+
+/// Staged by applying a `ProposalType::RotateValidators` proposal, waiting
+/// for `activates_at_height`. Promoted to `CURRENT_EPOCH + 1` in
+/// `VALIDATOR_SETS` and cleared the first time any governance entry point
+/// runs at or after that height (see `contract::maybe_promote_validator_set`).
+#[cw_serde]
+pub struct PendingValidatorSet {
+    pub validators: Vec<(Addr, u64)>,
+    pub activates_at_height: u64,
+}
+
+pub const PENDING_VALIDATOR_SET: Item<PendingValidatorSet> = Item::new("pending_validator_set");
+
+/// Initial lockout, in blocks, applied after a first vote — doubles with
+/// each further vote by the same account (a vote while the previous
+/// lockout is still running is rejected). The doubling is not reset by the
+/// lockout expiring or by an epoch change, only by reaching
+/// `MAX_LOCKOUT_HISTORY`. Mirrors Solana vote-state's `INITIAL_LOCKOUT`.
+pub const INITIAL_LOCKOUT_BLOCKS: u64 = 2;
+
+/// Cap on consecutive-vote doubling — mirrors Solana vote-state's
+/// `MAX_LOCKOUT_HISTORY` (the tower's max confirmation depth).
+pub const MAX_LOCKOUT_HISTORY: u32 = 31;
+
+/// Tower-BFT-inspired per-voter lockout state: voting again before
+/// `locked_until_height` is rejected with `ContractError::VoteLockedOut`,
+/// and each successful vote doubles the lockout up to
+/// `MAX_LOCKOUT_HISTORY` consecutive confirmations before it resets.
+#[cw_serde]
+pub struct VoterLockout {
+    pub confirmation_count: u32,
+    pub locked_until_height: u64,
+}
+
+pub const VOTE_LOCKOUTS: Map<&str, VoterLockout> = Map::new("vote_lockouts");
+
+/// Spent nullifiers per circuit, keyed `(circuit_id, nullifier)`, where
+/// `nullifier` is the raw bytes of the `public_inputs` entry at the
+/// circuit's `nullifier_index`. Presence means the underlying credential or
+/// note has already been consumed and any further proof reusing it must be
+/// rejected with `ContractError::NullifierAlreadySpent`. This is the
+/// replay/double-spend guard for one-time-use proofs: a circuit opts in by
+/// setting `Circuit::nullifier_index` at `RegisterCircuit`, and
+/// `SubmitProof`/`SubmitProofBatch`/`SubmitProofs` all insert here on a
+/// successful verification before accepting the proof.
+pub const NULLIFIERS: Map<(&str, &str), NullifierRecord> = Map::new("nullifiers");
+
+/// Audit record for a spent nullifier: who burned it and when, so
+/// `QueryMsg::NullifierStatus` can answer more than a bare yes/no.
+#[cw_serde]
+pub struct NullifierRecord {
+    pub circuit_id: String,
+    pub submitter: Addr,
+    pub spent_at_height: u64,
+}
+
+/// Native tokens collected via `Config::registration_fee`, keyed by denom.
+/// Drained by `ExecuteMsg::WithdrawFees`, `ADMIN_ROLE` only.
+pub const COLLECTED_FEES: Map<&str, cosmwasm_std::Uint128> = Map::new("collected_fees");
+
+/// Metric kind, mirroring the two OpenTelemetry instrument kinds this
+/// contract actually emits: monotonic counters and point-in-time gauges.
+#[cw_serde]
+pub enum MetricKind {
+    Counter,
+    Gauge,
+}
+
+/// A single named metric's current value and the kind it was first
+/// recorded as. `record_metric` (contract.rs) enforces that a name is
+/// always emitted as the same kind, erroring with
+/// `ContractError::MetricKindMismatch` otherwise - a counter can't
+/// quietly become a gauge under the same name.
+#[cw_serde]
+pub struct Metric {
+    pub kind: MetricKind,
+    pub value: u128,
+}
+
+/// Named operational counters/gauges - e.g. `proofs_submitted_total`,
+/// `proofs_verified_total`, `proofs_rejected_total`, and the
+/// `last_proof_submitted_at` gauge - emitted by the execute handlers that
+/// cause them, exported in bulk via `QueryMsg::MetricsSnapshot`.
+pub const METRICS: Map<&str, Metric> = Map::new("metrics");
+
+/// One audit-log record, appended by `append_audit_entry` whenever a
+/// tracked execute handler runs. `seq` doubles as the `AUDIT_LOG` key, so
+/// it's carried on the value too for callers that only have the entry
+/// itself (e.g. after a columnar export round-trip).
+#[cw_serde]
+pub struct AuditEntry {
+    pub seq: u64,
+    pub action: String,
+    pub actor: Addr,
+    pub circuit_id: String,
+    pub success: bool,
+    pub timestamp: u64,
+}
+
+/// Next free `AUDIT_LOG` key, i.e. the total number of audit entries ever
+/// appended. Kept as its own counter rather than derived from a
+/// `AUDIT_LOG` scan so appending stays O(1), the same reasoning behind
+/// `Config::total_proofs`.
+pub const AUDIT_SEQ: Item<u64> = Item::new("audit_seq");
+
+/// Append-only audit log, keyed by the `AuditEntry::seq` it was appended
+/// at. Exported in structure-of-arrays form by
+/// `QueryMsg::AuditBatchExport`, which is friendlier to bulk off-chain
+/// ingestion (a columnar store, a spreadsheet) than one JSON object per row.
+pub const AUDIT_LOG: Map<u64, AuditEntry> = Map::new("audit_log");
+
+/// Fixed depth of the incremental sparse Merkle tree this contract
+/// maintains per `tree_id` (one per `circuit_id` — see `MERKLE_NEXT_INDEX`
+/// below) — 2^20 leaves per tree, after which further inserts are
+/// rejected with `ContractError::MerkleIndexOutOfRange`.
+pub const MERKLE_TREE_DEPTH: u32 = 20;
+
+/// Sparse-Merkle-tree node hashes, storing only non-default (non-empty-
+/// subtree) nodes: `(tree_id, level, index_at_level) -> sha256 hash`.
+/// `level` 0 is the leaf layer; `level == MERKLE_TREE_DEPTH`, `index == 0`
+/// is the root. An absent entry means that node currently equals its
+/// depth's precomputed empty-subtree default, the same sparse-storage
+/// trick `RevocationAccumulator` avoids with its RSA accumulator instead.
+pub const MERKLE_NODES: Map<(&str, u32, u64), Binary> = Map::new("merkle_nodes");
+
+/// Next free leaf index per `tree_id`, i.e. that tree's current leaf
+/// count. Missing means `tree_id` has never had a leaf inserted — queries
+/// against it fail with `StdError::not_found`, matching how every other
+/// by-id query in this contract reports a missing key.
+pub const MERKLE_NEXT_INDEX: Map<&str, u64> = Map::new("merkle_next_index");
+
+/// An RSA accumulator over a revocable circuit's currently active (i.e.
+/// not-yet-revoked) credential indices: `value = base^(product of each
+/// active member's `revocation::member_exponent`) mod modulus`. See
+/// `crate::revocation` for the accumulator math and
+/// `contract::execute_revoke_credential` for how it's updated.
+#[cw_serde]
+pub struct RevocationAccumulator {
+    pub modulus: String,
+    pub base: String,
+    pub value: String,
+    pub epoch: u64,
+}
+
+pub const REVOCATION_ACCUMULATORS: Map<&str, RevocationAccumulator> = Map::new("revocation_accumulators");
+
+/// Credential indices currently folded into a circuit's
+/// `RevocationAccumulator`, keyed `(circuit_id, credential_index)`.
+/// Presence (regardless of the stored `bool`) means "active"; removed by
+/// `execute_revoke_credential` when the credential is revoked.
+pub const ACTIVE_CREDENTIALS: Map<(&str, u32), bool> = Map::new("active_credentials");
+
+/// Tombstone for a revoked `(circuit_id, credential_index)`, so a revoked
+/// index can never be re-enrolled through `SubmitProof`'s lazy-enrollment
+/// path.
+pub const REVOKED_CREDENTIALS: Map<(&str, u32), bool> = Map::new("revoked_credentials");
 
 #[cw_serde]
 pub struct GovernanceProposal {
@@ -92,10 +901,155 @@ pub struct GovernanceProposal {
     pub proposal_type: ProposalType,
     pub proposer: Addr,
     pub created_at: u64,
+    /// Set to `u64::MAX` while the proposal is in `ProposalStatus::Draft`
+    /// (sentinel so `VotingPeriodEnded` checks never fire on an unopened
+    /// proposal), then overwritten with the real deadline the moment every
+    /// `signatories` entry signs off, per `voting_period`.
     pub voting_end: u64,
     pub executed: bool,
+    /// Stake-weighted sum of `VOTING_POWER` for every account that voted
+    /// yes, replacing the old Sybil-able one-account-one-vote tally.
     pub votes_for: u64,
     pub votes_against: u64,
+    /// Stake-weighted sum of `VOTING_POWER` for every account that voted
+    /// `VoteChoice::Abstain`. Counts toward `quorum_fraction` participation,
+    /// but not toward `approval_threshold`'s Yes/No ratio.
+    #[serde(default)]
+    pub votes_abstain: u64,
+    /// Minimum `votes_for + votes_against + votes_abstain` for the
+    /// proposal to be executable at all, copied from
+    /// `Config::default_quorum_threshold` at submission time.
+    pub quorum_threshold: u64,
+    /// Minimum `votes_for` for the proposal to pass once quorum is met,
+    /// copied from `Config::default_pass_threshold` at submission time.
+    pub pass_threshold: u64,
+    /// Minimum fraction of `total_eligible_weight` that must have
+    /// participated, copied from `Config::default_quorum_fraction` at
+    /// submission time. An additional AND-gate alongside `quorum_threshold`.
+    #[serde(default)]
+    pub quorum_fraction: Decimal,
+    /// Minimum fraction of `votes_for` out of `votes_for + votes_against`,
+    /// copied from `Config::default_threshold_fraction` at submission time.
+    /// An additional AND-gate alongside `pass_threshold`.
+    #[serde(default)]
+    pub approval_threshold: Decimal,
+    /// Sum of `VOTING_POWER` (or `Config::default_voting_power`) across
+    /// every `GOVERNANCE_ROLE` member at submission time (or, once a
+    /// validator set is active, the `creation_epoch` set's total weight), so
+    /// a later membership or weight change can't retroactively change how
+    /// close a proposal came to quorum. The denominator `quorum_fraction` is
+    /// checked against; `0` makes that fractional gate trivially satisfied.
+    pub total_eligible_weight: u64,
+    /// `access_control::TIMELOCK_TRANSACTIONS` id this proposal's effect was
+    /// queued under once it passed, when `Config::timelock_enabled`. `None`
+    /// until the proposal passes, or for the lifetime of a proposal that
+    /// passed with timelocking disabled (its effect applied immediately).
+    #[serde(default)]
+    pub scheduled_transaction_id: Option<u64>,
+    /// Floor on the timelock delay applied when this proposal is queued,
+    /// submitted alongside the proposal itself. Only ever raises the
+    /// effective delay above `Config::min_timelock_delay`, never below it.
+    #[serde(default)]
+    pub requested_delay: Option<u64>,
+    /// Funds escrowed by the proposer at submission time, per
+    /// `Config::proposal_deposit`. `None` if proposals were free at
+    /// submission time.
+    #[serde(default)]
+    pub deposit: Option<Coin>,
+    /// Whether `deposit` has already been resolved via
+    /// `ExecuteMsg::RefundProposalDeposit` — returned to the proposer, or
+    /// forfeited under `DepositRefundPolicy::Never` — so it can't be
+    /// claimed twice.
+    #[serde(default)]
+    pub deposit_refunded: bool,
+    /// Distinct `Config::multisig_config` signers that have called
+    /// `ExecuteMsg::ApproveProposal`. When multisig is enabled, execution
+    /// additionally requires `approvals.len() >= multisig.threshold`, on
+    /// top of the DAO vote tally passing on its own.
+    #[serde(default)]
+    pub approvals: Vec<Addr>,
+    /// `CURRENT_EPOCH` at submission time, pinning which `VALIDATOR_SETS`
+    /// entry this proposal's weights and quorum are tallied against (see
+    /// `contract::voting_power`). Real epochs start at `1`; `0` means no
+    /// `ProposalType::RotateValidators` has ever been promoted, so this
+    /// proposal falls back to the pre-existing `GOVERNANCE_ROLE`/
+    /// `VOTING_POWER` weighting instead.
+    #[serde(default)]
+    pub creation_epoch: u64,
+    /// Required co-sponsors who must each call
+    /// `ExecuteMsg::SignOffProposal` before voting opens, the
+    /// spl-governance-style review/endorsement gate. `(signatory, signed)`;
+    /// empty means the proposal skips `ProposalStatus::Draft` entirely,
+    /// preserving the historical immediate-voting behavior.
+    #[serde(default)]
+    pub signatories: Vec<(Addr, bool)>,
+    /// The `voting_period` this proposal was submitted with, stashed so the
+    /// last `SignOffProposal` call can compute the real `voting_end` at
+    /// sign-off time rather than at submission time.
+    #[serde(default)]
+    pub voting_period: u64,
+    /// Ordered on-chain messages this proposal enacts on
+    /// `ExecuteMsg::ExecuteProposal`, the spl-governance proposal-instruction
+    /// model. Dispatched as ordered `CosmosMsg`s alongside `proposal_type`'s
+    /// effect (not instead of it); empty for proposals that only carry the
+    /// coarse `proposal_type` change.
+    #[serde(default)]
+    pub instructions: Vec<ProposalInstruction>,
+}
+
+/// One ordered, self-enacting step of a `GovernanceProposal`, spl-governance's
+/// proposal-instruction model. `msg` is dispatched verbatim via
+/// `Response::add_message` when the proposal executes; CosmWasm's native
+/// transaction atomicity gives "halt on first failure" for free — if any
+/// instruction's message errors, the whole `ExecuteProposal`/
+/// `ApplyGovernanceProposal` call reverts and `executed` never gets set on
+/// any of them.
+#[cw_serde]
+pub struct ProposalInstruction {
+    pub msg: CosmosMsg,
+    #[serde(default)]
+    pub executed: bool,
+}
+
+/// Page direction for `QueryMsg::Proposals`. A thin, serializable stand-in
+/// for `cosmwasm_std::Order`, which isn't itself `Serialize`/`Deserialize`.
+#[cw_serde]
+#[derive(Default)]
+pub enum SortOrder {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+/// Derived lifecycle status of a `GovernanceProposal`, recomputed at query
+/// time from `executed`/`voting_end`/the vote tallies rather than stored
+/// (mirrors `TimelockStatus`). Lets `QueryMsg::Proposals` filter for
+/// actionable proposals without every client re-deriving the same logic.
+#[cw_serde]
+pub enum ProposalStatus {
+    /// Awaiting sign-off from every `GovernanceProposal::signatories` entry
+    /// via `ExecuteMsg::SignOffProposal`; `voting_end` hasn't started
+    /// counting yet. Only reachable when signatories were attached at
+    /// submission time — a proposal with none skips straight to `Open`.
+    Draft,
+    /// `voting_end` hasn't passed yet.
+    Open,
+    /// Voting ended, quorum and pass threshold were both met, and
+    /// `ExecuteMsg::ExecuteProposal` has queued the proposal's effect
+    /// behind the `Config::min_timelock_delay` window (see
+    /// `GovernanceProposal::scheduled_transaction_id`). Not yet executable;
+    /// `ExecuteMsg::CancelScheduledProposal` can still withdraw it.
+    Queued,
+    /// Voting ended, quorum and pass threshold were both met, and the
+    /// proposal has not yet been queued or executed. Only reachable when
+    /// `Config::timelock_enabled` is `false` (the only case where passing
+    /// doesn't queue the proposal in the same call) or in the instant
+    /// between a proposal passing and anyone calling `ExecuteProposal`.
+    Passed,
+    /// Voting ended, but quorum or pass threshold was not met.
+    Rejected,
+    /// `ApplyGovernanceProposal`/`ExecuteProposal` already applied its effect.
+    Executed,
 }
 
 #[cw_serde]
@@ -103,6 +1057,8 @@ pub enum ProposalType {
     AddIssuer {
         issuer_address: String,
         authorized_circuits: Vec<String>,
+        #[serde(default)]
+        expires_at: Option<u64>,
     },
     RemoveIssuer {
         issuer_address: String,
@@ -113,4 +1069,83 @@ pub enum ProposalType {
     DeactivateCircuit {
         circuit_id: String,
     },
+    /// Transfer `Config::admin`, gated the same way a direct
+    /// `ExecuteMsg::UpdateAdmin` call is outside governance.
+    UpdateAdmin {
+        new_admin: String,
+    },
+    /// Retune `Config::registration_fee`, gated the same way a direct
+    /// `ExecuteMsg::UpdateFees` call is outside governance.
+    UpdateFees {
+        registration_fee: Option<Coin>,
+    },
+    /// Lets the DAO itself retune its own governance parameters, instead of
+    /// only `config.admin` being able to. Every field is optional so a
+    /// proposal only needs to name the parameters it actually changes.
+    UpdateConfig {
+        default_quorum_threshold: Option<u64>,
+        default_pass_threshold: Option<u64>,
+        default_voting_power: Option<u64>,
+    },
+    /// Add a signer to `Config::multisig_config`, making the safety
+    /// council roster itself governable rather than fixed at instantiation.
+    AddMultisigMember {
+        member: String,
+    },
+    /// Remove a signer from `Config::multisig_config`.
+    RemoveMultisigMember {
+        member: String,
+    },
+    /// Fairly select a committee of size `k` from `candidates` (e.g. an
+    /// audit committee or a rotating issuer set) using a drand-style
+    /// randomness beacon. Execution only records a pending
+    /// `RandomnessRequest`; the committee itself is chosen once
+    /// `ExecuteMsg::ReceiveRandomness` delivers the beacon.
+    SelectIssuerCommittee {
+        candidates: Vec<String>,
+        k: u32,
+    },
+    /// Stage a new weighted `ValidatorSet`, promoted from
+    /// `PENDING_VALIDATOR_SET` to the active `CURRENT_EPOCH` the first time
+    /// any governance entry point runs at or after `activate_at_height`
+    /// (see `maybe_promote_validator_set`). Applying this proposal only
+    /// stages the set; it does not itself advance `CURRENT_EPOCH`, so a
+    /// vote already open against the outgoing epoch can't have its outcome
+    /// changed by a rotation landing mid-vote.
+    RotateValidators {
+        validators: Vec<(String, u64)>,
+        activate_at_height: u64,
+    },
+}
+
+/// A `ProposalType::SelectIssuerCommittee` proposal's execution, waiting on
+/// a beacon from `Config::randomness_provider`. Keyed by proposal id in
+/// `RANDOMNESS_REQUESTS`.
+#[cw_serde]
+pub struct RandomnessRequest {
+    pub candidates: Vec<Addr>,
+    pub k: u32,
+    pub requested_at: u64,
+    pub fulfilled: bool,
+}
+
+/// Outbound message sent to `Config::randomness_provider` when a
+/// `SelectIssuerCommittee` proposal executes, asking it to later call back
+/// with `ExecuteMsg::ReceiveRandomness { proposal_id, randomness }`.
+#[cw_serde]
+pub enum RandomnessProviderMsg {
+    RequestRandomness { proposal_id: u64 },
+}
+
+/// The resolved outcome of a `SelectIssuerCommittee` proposal: the beacon
+/// `ReceiveRandomness` delivered and the committee it deterministically
+/// selected. Kept around (rather than discarded once applied) so anyone can
+/// re-derive and audit the same committee from `(beacon, candidates, k)`.
+#[cw_serde]
+pub struct IssuerCommittee {
+    pub candidates: Vec<Addr>,
+    pub k: u32,
+    pub beacon: Binary,
+    pub committee: Vec<Addr>,
+    pub fulfilled_at: u64,
 }
\ No newline at end of file
@@ -1,3 +1,4 @@
+use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{StdError, StdResult};
 use crate::error::ContractError;
 
@@ -8,9 +9,20 @@ use ark_ec::AffineRepr;
 use serde_json::Value;
 use num_bigint::BigUint;
 use num_traits::Num;
+#[cfg(feature = "production-verification")]
+use base64::{engine::general_purpose, Engine as _};
 
 /// Production Groth16 proof verification using arkworks
 /// Compatible with snarkjs proof format
+///
+/// Genuine BN254 pairing verification (parse `verification_key`/`proof`
+/// into arkworks VK/proof types, compute `vk_x = IC[0] + Σ a_i·IC[i]`, and
+/// accept iff `e(A, B) = e(α, β)·e(vk_x, γ)·e(C, δ)`, checked as a single
+/// multi-Miller-loop/final-exponentiation) lives in [`verify_groth16_proof`]
+/// and is used whenever this crate is built with the
+/// `production-verification` feature; [`verify_proof_simplified`] is a
+/// format-only heuristic fallback for builds/tests without real proof
+/// fixtures and must never gate a mainnet deployment.
 pub fn verify_proof(
     verification_key: &str,
     public_inputs: &[String],
@@ -29,6 +41,63 @@ pub fn verify_proof(
     }
 }
 
+/// Sibling of [`verify_proof`] that verifies against a
+/// `Circuit::prepared_verifying_key` (produced by
+/// [`compute_prepared_verifying_key`]) instead of the raw
+/// `verification_key`, so the fixed pairings `prepare_verifying_key`
+/// already computed at registration aren't redone on every proof — the
+/// per-proof cost really is just the `vk_x` linear combination and one
+/// pairing check.
+pub fn verify_proof_with_prepared_vk(
+    prepared_verifying_key: &str,
+    public_inputs: &[String],
+    proof: &str,
+) -> Result<bool, ContractError> {
+    #[cfg(not(feature = "production-verification"))]
+    {
+        return verify_proof_simplified(prepared_verifying_key, public_inputs, proof);
+    }
+
+    #[cfg(feature = "production-verification")]
+    {
+        verify_groth16_proof_prepared(prepared_verifying_key, public_inputs, proof)
+    }
+}
+
+#[cfg(feature = "production-verification")]
+fn verify_groth16_proof_prepared(
+    prepared_verifying_key: &str,
+    public_inputs: &[String],
+    proof: &str,
+) -> Result<bool, ContractError> {
+    use ark_groth16::{Groth16, PreparedVerifyingKey};
+    use ark_serialize::CanonicalDeserialize;
+
+    let bytes = general_purpose::STANDARD
+        .decode(prepared_verifying_key)
+        .map_err(|_| ContractError::InvalidVerificationKey {})?;
+    let pvk = PreparedVerifyingKey::<Bn254>::deserialize_compressed(&bytes[..])
+        .map_err(|_| ContractError::InvalidVerificationKey {})?;
+
+    // `vk.gamma_abc_g1` (IC) has one entry per public input plus the
+    // constant term, same check as the unprepared path.
+    let expected_public_inputs = pvk.vk.gamma_abc_g1.len().saturating_sub(1);
+    if public_inputs.len() != expected_public_inputs {
+        return Err(ContractError::PublicInputCountMismatch {
+            expected: expected_public_inputs,
+            got: public_inputs.len(),
+        });
+    }
+
+    let groth16_proof = parse_snarkjs_proof(proof)?;
+    let field_inputs = parse_public_inputs(public_inputs)?;
+
+    match Groth16::<Bn254>::verify_proof(&pvk, &groth16_proof, &field_inputs) {
+        Ok(valid) => Ok(valid),
+        Err(_) => Ok(false),
+    }
+}
+
 /// Real Groth16 verification using arkworks (BN254 curve)
 #[cfg(feature = "production-verification")]
 fn verify_groth16_proof(
@@ -41,13 +110,24 @@ fn verify_groth16_proof(
 
     // Parse the verification key from JSON format
     let vk = parse_verification_key(verification_key)?;
-    
+
+    // A circuit's IC (gamma_abc_g1) has one entry per public input plus the
+    // constant term, so IC.len() - 1 is the number of public inputs the VK
+    // expects. Reject a mismatch before doing the (expensive) pairing.
+    let expected_public_inputs = vk.gamma_abc_g1.len().saturating_sub(1);
+    if public_inputs.len() != expected_public_inputs {
+        return Err(ContractError::PublicInputCountMismatch {
+            expected: expected_public_inputs,
+            got: public_inputs.len(),
+        });
+    }
+
     // Parse the proof from snarkjs JSON format
     let groth16_proof = parse_snarkjs_proof(proof)?;
-    
+
     // Parse public inputs to field elements
     let field_inputs = parse_public_inputs(public_inputs)?;
-    
+
     // Perform Groth16 verification
     match Groth16::<Bn254>::verify(&vk, &field_inputs, &groth16_proof) {
         Ok(valid) => Ok(valid),
@@ -55,19 +135,34 @@ fn verify_groth16_proof(
     }
 }
 
-/// Parse snarkjs verification key JSON format
+/// Parse snarkjs verification key JSON format, following the circom JSON
+/// schema handling in zkutil: reject anything that isn't a Groth16/BN254 key
+/// up front rather than silently misinterpreting the curve points, and
+/// cross-check `nPublic` (when present) against the actual `IC` length.
 #[cfg(feature = "production-verification")]
 fn parse_verification_key(vk_str: &str) -> Result<VerifyingKey<Bn254>, ContractError> {
     // Parse JSON
     let vk_json: Value = serde_json::from_str(vk_str)
         .map_err(|_| ContractError::InvalidVerificationKey {})?;
-    
+
+    if let Some(protocol) = vk_json.get("protocol").and_then(Value::as_str) {
+        if protocol != "groth16" {
+            return Err(ContractError::UnsupportedProtocol { protocol: protocol.to_string() });
+        }
+    }
+
+    if let Some(curve) = vk_json.get("curve").and_then(Value::as_str) {
+        if curve != "bn128" && curve != "bn254" {
+            return Err(ContractError::CurveMismatch { curve: curve.to_string() });
+        }
+    }
+
     // Extract verification key components
     let alpha_g1 = parse_g1_point(&vk_json["vk_alpha_1"])?;
     let beta_g2 = parse_g2_point(&vk_json["vk_beta_2"])?;
     let gamma_g2 = parse_g2_point(&vk_json["vk_gamma_2"])?;
     let delta_g2 = parse_g2_point(&vk_json["vk_delta_2"])?;
-    
+
     // Parse gamma_abc_g1 points
     let gamma_abc_g1: Result<Vec<_>, _> = vk_json["IC"]
         .as_array()
@@ -75,9 +170,19 @@ fn parse_verification_key(vk_str: &str) -> Result<VerifyingKey<Bn254>, ContractE
         .iter()
         .map(parse_g1_point)
         .collect();
-    
+
     let gamma_abc_g1 = gamma_abc_g1?;
-    
+
+    if let Some(n_public) = vk_json.get("nPublic").and_then(Value::as_u64) {
+        let expected = gamma_abc_g1.len().saturating_sub(1);
+        if n_public as usize != expected {
+            return Err(ContractError::PublicInputCountMismatch {
+                expected,
+                got: n_public as usize,
+            });
+        }
+    }
+
     Ok(VerifyingKey {
         alpha_g1,
         beta_g2,
@@ -100,42 +205,54 @@ fn parse_snarkjs_proof(proof_str: &str) -> Result<Proof<Bn254>, ContractError> {
     Ok(Proof { a, b, c })
 }
 
-/// Parse G1 point from snarkjs format
+/// Parse G1 point from snarkjs format, rejecting off-curve or
+/// wrong-subgroup points rather than trusting the caller-supplied
+/// coordinates.
 #[cfg(feature = "production-verification")]
 fn parse_g1_point(point_json: &Value) -> Result<G1Affine, ContractError> {
     let coords = point_json.as_array()
         .ok_or(ContractError::InvalidVerificationKey {})?;
-    
+
     if coords.len() != 3 {
         return Err(ContractError::InvalidVerificationKey {});
     }
-    
+
     let x = parse_fq_element(&coords[0])?;
     let y = parse_fq_element(&coords[1])?;
     let z = parse_fq_element(&coords[2])?;
-    
+
     // Convert from projective to affine coordinates
-    if z.is_zero() {
-        Ok(G1Affine::identity())
+    let point = if z.is_zero() {
+        G1Affine::identity()
     } else {
         let z_inv = z.inverse().ok_or(ContractError::InvalidVerificationKey {})?;
         let x_affine = x * z_inv;
         let y_affine = y * z_inv;
-        
-        Ok(G1Affine::new_unchecked(x_affine, y_affine))
+
+        G1Affine::new_unchecked(x_affine, y_affine)
+    };
+
+    if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(ContractError::InvalidVerificationKey {});
     }
+
+    Ok(point)
 }
 
-/// Parse G2 point from snarkjs format
+/// Parse G2 point from snarkjs format. `x`/`y`/`z` are each `Fq2` (a
+/// `[c0, c1]` pair), so the projective-to-affine division is genuine Fq2
+/// field arithmetic, not a per-limb copy — dividing by `z` component-wise
+/// would produce the wrong point whenever `z != 1`. Rejects off-curve or
+/// wrong-subgroup points.
 #[cfg(feature = "production-verification")]
 fn parse_g2_point(point_json: &Value) -> Result<G2Affine, ContractError> {
     let coords = point_json.as_array()
         .ok_or(ContractError::InvalidVerificationKey {})?;
-    
+
     if coords.len() != 3 {
         return Err(ContractError::InvalidVerificationKey {});
     }
-    
+
     // G2 coordinates are arrays of 2 elements each
     let x_coords = coords[0].as_array()
         .ok_or(ContractError::InvalidVerificationKey {})?;
@@ -143,45 +260,32 @@ fn parse_g2_point(point_json: &Value) -> Result<G2Affine, ContractError> {
         .ok_or(ContractError::InvalidVerificationKey {})?;
     let z_coords = coords[2].as_array()
         .ok_or(ContractError::InvalidVerificationKey {})?;
-    
+
     let x_c0 = parse_fq_element(&x_coords[0])?;
     let x_c1 = parse_fq_element(&x_coords[1])?;
     let y_c0 = parse_fq_element(&y_coords[0])?;
     let y_c1 = parse_fq_element(&y_coords[1])?;
     let z_c0 = parse_fq_element(&z_coords[0])?;
     let z_c1 = parse_fq_element(&z_coords[1])?;
-    
-    // Convert to affine coordinates (simplified for this example)
-    // In production, would handle the full Fq2 field arithmetic
-    if z_c0.is_zero() && z_c1.is_zero() {
-        Ok(G2Affine::identity())
-    } else {
-        // Simplified conversion - in production would implement full Fq2 division
-        Ok(G2Affine::new_unchecked(
-            ark_bn254::Fq2::new(x_c0, x_c1),
-            ark_bn254::Fq2::new(y_c0, y_c1),
-        ))
-    }
-}
 
-/// Parse field element from string representation (Fr field)
-#[cfg(feature = "production-verification")]
-fn parse_field_element(value: &Value) -> Result<Fr, ContractError> {
-    let s = value.as_str()
-        .ok_or(ContractError::InvalidVerificationKey {})?;
-    
-    if s.starts_with("0x") {
-        // Hex format
-        let hex_str = &s[2..];
-        let big_int = BigUint::from_str_radix(hex_str, 16)
-            .map_err(|_| ContractError::InvalidVerificationKey {})?;
-        let bytes = big_int.to_bytes_le();
-        Ok(Fr::from_le_bytes_mod_order(&bytes))
+    let z = ark_bn254::Fq2::new(z_c0, z_c1);
+
+    // Convert from projective to affine coordinates
+    let point = if z.is_zero() {
+        G2Affine::identity()
     } else {
-        // Decimal format
-        s.parse::<Fr>()
-            .map_err(|_| ContractError::InvalidVerificationKey {})
+        let z_inv = z.inverse().ok_or(ContractError::InvalidVerificationKey {})?;
+        let x_affine = ark_bn254::Fq2::new(x_c0, x_c1) * z_inv;
+        let y_affine = ark_bn254::Fq2::new(y_c0, y_c1) * z_inv;
+
+        G2Affine::new_unchecked(x_affine, y_affine)
+    };
+
+    if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(ContractError::InvalidVerificationKey {});
     }
+
+    Ok(point)
 }
 
 /// Parse base field element from string representation (Fq field)
@@ -223,6 +327,474 @@ fn parse_public_inputs(inputs: &[String]) -> Result<Vec<Fr>, ContractError> {
         .collect()
 }
 
+/// Batch-verify several proofs sharing one verifying key, aggregating them
+/// into a single final exponentiation instead of one per proof — mirrors
+/// the `BatchVerifier` pattern from the halo2/snark-verifier ecosystem.
+///
+/// For each proof `i` compute the input commitment
+/// `vk_x_i = IC[0] + Σ_j input_{i,j}·IC[j+1]`, draw an independent scalar
+/// `r_i` by hashing all proof bytes (so the combination is non-interactive
+/// and every validator replaying this message derives the same scalars),
+/// then accumulate one multi-Miller loop over
+/// `(r_i·A_i, B_i)`, `(−r_i·vk_x_i, γ_g2)`, `(−r_i·C_i, δ_g2)`, plus the
+/// aggregated term `(−(Σ r_i)·α_g1, β_g2)`. The batch is valid iff the
+/// final exponentiation of that product is the identity in the target group.
+#[cfg(feature = "production-verification")]
+pub fn verify_groth16_batch(
+    verification_key: &str,
+    proofs: &[(Vec<String>, String)],
+) -> Result<bool, ContractError> {
+    use ark_ec::pairing::Pairing;
+    use ark_ec::CurveGroup;
+    use ark_ff::PrimeField as _;
+
+    if proofs.is_empty() {
+        return Ok(true);
+    }
+
+    let vk = parse_verification_key(verification_key)?;
+
+    let scalars = derive_batch_scalars(proofs);
+
+    let mut g1_terms: Vec<G1Affine> = Vec::with_capacity(proofs.len() * 3 + 1);
+    let mut g2_terms: Vec<G2Affine> = Vec::with_capacity(proofs.len() * 3 + 1);
+    let mut r_sum = Fr::zero();
+
+    for ((public_inputs, proof_str), r_i) in proofs.iter().zip(scalars.iter()) {
+        let groth16_proof = parse_snarkjs_proof(proof_str)?;
+        let field_inputs = parse_public_inputs(public_inputs)?;
+
+        if field_inputs.len() + 1 != vk.gamma_abc_g1.len() {
+            return Err(ContractError::InvalidPublicInputs {});
+        }
+
+        let mut vk_x = vk.gamma_abc_g1[0].into_group();
+        for (input, ic) in field_inputs.iter().zip(vk.gamma_abc_g1.iter().skip(1)) {
+            vk_x += ic.mul_bigint(input.into_bigint());
+        }
+        let vk_x = vk_x.into_affine();
+
+        g1_terms.push(groth16_proof.a.mul_bigint(r_i.into_bigint()).into_affine());
+        g2_terms.push(groth16_proof.b);
+
+        g1_terms.push((-vk_x).mul_bigint(r_i.into_bigint()).into_affine());
+        g2_terms.push(vk.gamma_g2);
+
+        g1_terms.push((-groth16_proof.c).mul_bigint(r_i.into_bigint()).into_affine());
+        g2_terms.push(vk.delta_g2);
+
+        r_sum += r_i;
+    }
+
+    g1_terms.push((-vk.alpha_g1).mul_bigint(r_sum.into_bigint()).into_affine());
+    g2_terms.push(vk.beta_g2);
+
+    let miller_loop_result = Bn254::multi_miller_loop(g1_terms, g2_terms);
+    let result = Bn254::final_exponentiation(miller_loop_result)
+        .ok_or(ContractError::ProofVerificationFailed {})?;
+
+    Ok(result.is_zero())
+}
+
+/// Derive one random scalar per proof, non-interactively and
+/// deterministically, by hashing every proof in the batch together with
+/// the target proof's index — so two validators replaying the same
+/// `SubmitProofBatch` message always agree on the scalars.
+#[cfg(feature = "production-verification")]
+fn derive_batch_scalars(proofs: &[(Vec<String>, String)]) -> Vec<Fr> {
+    use sha2::{Digest, Sha256};
+
+    let mut base_hasher = Sha256::new();
+    for (inputs, proof_str) in proofs {
+        for input in inputs {
+            base_hasher.update(input.as_bytes());
+        }
+        base_hasher.update(proof_str.as_bytes());
+    }
+    let base_digest = base_hasher.finalize();
+
+    (0..proofs.len())
+        .map(|i| {
+            let mut hasher = Sha256::new();
+            hasher.update(base_digest);
+            hasher.update((i as u64).to_be_bytes());
+            Fr::from_le_bytes_mod_order(&hasher.finalize())
+        })
+        .collect()
+}
+
+/// Batch sibling of [`verify_proof`]: verifies `proofs` (each a
+/// `(public_inputs, proof)` pair) against one `verification_key`. Uses
+/// aggregated Groth16 verification in production, or falls back to
+/// sequential [`verify_proof_simplified`] calls otherwise.
+pub fn verify_proof_batch(
+    verification_key: &str,
+    proofs: &[(Vec<String>, String)],
+) -> Result<bool, ContractError> {
+    #[cfg(not(feature = "production-verification"))]
+    {
+        for (public_inputs, proof) in proofs {
+            if !verify_proof_simplified(verification_key, public_inputs, proof)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    #[cfg(feature = "production-verification")]
+    {
+        verify_groth16_batch(verification_key, proofs)
+    }
+}
+
+/// Precompute a `PreparedVerifyingKey` at `RegisterCircuit` time so every
+/// `SubmitProof` call skips recomputing the fixed `e(alpha_g1, beta_g2)`
+/// pairing and the prepared negations of `gamma_g2`/`delta_g2` — the same
+/// optimization bellman's `prepare_verifying_key` applies. The result is
+/// arkworks-serialized (compressed) and base64-encoded for storage
+/// alongside the raw `verification_key` in `Circuit`.
+#[cfg(feature = "production-verification")]
+pub fn compute_prepared_verifying_key(verification_key: &str) -> Result<String, ContractError> {
+    use ark_serialize::CanonicalSerialize;
+
+    let vk = parse_verification_key(verification_key)?;
+    let pvk = ark_groth16::prepare_verifying_key(&vk);
+
+    let mut bytes = Vec::new();
+    pvk.serialize_compressed(&mut bytes)
+        .map_err(|_| ContractError::InvalidVerificationKey {})?;
+
+    Ok(general_purpose::STANDARD.encode(bytes))
+}
+
+/// Parse a single `Fr` from a decimal or `0x`-hex string, the same two
+/// formats `parse_public_inputs` accepts — used for
+/// circuit-supplied Poseidon parameters, which come from `state::PoseidonParams`
+/// rather than proof/VK JSON.
+#[cfg(feature = "production-verification")]
+fn fr_from_str(s: &str) -> Result<Fr, ContractError> {
+    if let Some(hex_str) = s.strip_prefix("0x") {
+        let big_int = BigUint::from_str_radix(hex_str, 16)
+            .map_err(|_| ContractError::InvalidVerificationKey {})?;
+        Ok(Fr::from_le_bytes_mod_order(&big_int.to_bytes_le()))
+    } else {
+        s.parse::<Fr>().map_err(|_| ContractError::InvalidVerificationKey {})
+    }
+}
+
+#[cfg(feature = "production-verification")]
+fn build_poseidon_config(
+    params: &crate::state::PoseidonParams,
+) -> Result<ark_crypto_primitives::sponge::poseidon::PoseidonConfig<Fr>, ContractError> {
+    let parse_matrix = |rows: &[Vec<String>]| -> Result<Vec<Vec<Fr>>, ContractError> {
+        rows.iter()
+            .map(|row| row.iter().map(|s| fr_from_str(s)).collect())
+            .collect()
+    };
+
+    Ok(ark_crypto_primitives::sponge::poseidon::PoseidonConfig {
+        full_rounds: params.full_rounds as usize,
+        partial_rounds: params.partial_rounds as usize,
+        alpha: params.alpha,
+        ark: parse_matrix(&params.ark)?,
+        mds: parse_matrix(&params.mds)?,
+        rate: params.rate as usize,
+        capacity: params.capacity as usize,
+    })
+}
+
+/// Recompute a Poseidon sponge commitment over `public_inputs` per
+/// `policy` (mirroring the testudo `PoseidonTranscript` sponge-over-`Fr`
+/// construction) and check it equals `public_inputs[policy.commitment_index]`.
+/// Lets a circuit cryptographically bind many public inputs into a single
+/// on-chain commitment without trusting how the submitter framed them.
+pub fn verify_poseidon_commitment(
+    public_inputs: &[String],
+    policy: &crate::state::CommitmentPolicy,
+) -> Result<bool, ContractError> {
+    let index = policy.commitment_index as usize;
+    let declared = public_inputs.get(index).ok_or(ContractError::CommitmentIndexOutOfRange {
+        index: policy.commitment_index,
+        len: public_inputs.len(),
+    })?;
+
+    #[cfg(not(feature = "production-verification"))]
+    {
+        let _ = declared;
+        Ok(true)
+    }
+
+    #[cfg(feature = "production-verification")]
+    {
+        use ark_crypto_primitives::sponge::{poseidon::PoseidonSponge, CryptographicSponge};
+
+        let declared_fr = fr_from_str(declared)?;
+        let config = build_poseidon_config(&policy.poseidon)?;
+        let field_inputs = parse_public_inputs(public_inputs)?;
+
+        let mut sponge = PoseidonSponge::new(&config);
+        for fe in &field_inputs {
+            sponge.absorb(fe);
+        }
+        let squeezed: Vec<Fr> = sponge.squeeze_field_elements(1);
+
+        Ok(squeezed[0] == declared_fr)
+    }
+}
+
+/// Codec tag distinguishing the verbose snarkjs JSON encoding from a
+/// compact compressed-binary one, mirroring bellman's
+/// `into_compressed`/`into_affine` point serialization, plus a
+/// [`StructuredProof`]/[`StructuredVerifyingKey`] encoding serialized
+/// through serde instead of either.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProofEncoding {
+    Json,
+    CompressedBinary,
+    Structured,
+}
+
+/// A Groth16 proof as an explicit, serde-derived type instead of a JSON
+/// string hand-walked field by field. Every field element is a decimal or
+/// `0x`-hex string — never a numeric type — so the schema can't round-trip
+/// through an `f64` and trip the wasm module's no-floating-point-
+/// instructions rule. Serialized to [`cosmwasm_std::Binary`] for
+/// `ExecuteMsg::SubmitProofEncoded { encoding: ProofEncoding::Structured }`.
+#[cw_serde]
+pub struct StructuredProof {
+    pub pi_a: [String; 2],
+    pub pi_b: [[String; 2]; 2],
+    pub pi_c: [String; 2],
+}
+
+/// A Groth16 verifying key as an explicit, serde-derived type, the
+/// registration-time counterpart to [`StructuredProof`]. Same no-float
+/// constraint: every field element is a decimal or `0x`-hex string.
+#[cw_serde]
+pub struct StructuredVerifyingKey {
+    pub alpha_g1: [String; 2],
+    pub beta_g2: [[String; 2]; 2],
+    pub gamma_g2: [[String; 2]; 2],
+    pub delta_g2: [[String; 2]; 2],
+    pub ic: Vec<[String; 2]>,
+}
+
+/// Parse a [`StructuredProof`] straight from its typed fields — no
+/// `serde_json::Value` indexing, the deserialization itself already did
+/// the shape validation.
+#[cfg(feature = "production-verification")]
+fn parse_structured_proof(proof: &StructuredProof) -> Result<Proof<Bn254>, ContractError> {
+    let a = parse_g1_affine(&proof.pi_a)?;
+    let b = parse_g2_affine(&proof.pi_b)?;
+    let c = parse_g1_affine(&proof.pi_c)?;
+    Ok(Proof { a, b, c })
+}
+
+/// Parse a [`StructuredVerifyingKey`] straight from its typed fields.
+#[cfg(feature = "production-verification")]
+fn parse_structured_verifying_key(vk: &StructuredVerifyingKey) -> Result<VerifyingKey<Bn254>, ContractError> {
+    if vk.ic.is_empty() {
+        return Err(ContractError::InvalidVerificationKey {});
+    }
+    let alpha_g1 = parse_g1_affine(&vk.alpha_g1)?;
+    let beta_g2 = parse_g2_affine(&vk.beta_g2)?;
+    let gamma_g2 = parse_g2_affine(&vk.gamma_g2)?;
+    let delta_g2 = parse_g2_affine(&vk.delta_g2)?;
+    let gamma_abc_g1 = vk.ic.iter().map(parse_g1_affine).collect::<Result<Vec<_>, _>>()?;
+
+    Ok(VerifyingKey { alpha_g1, beta_g2, gamma_g2, delta_g2, gamma_abc_g1 })
+}
+
+/// Affine G1 point from a `[x, y]` pair of decimal/hex strings. Unlike
+/// [`parse_g1_point`]'s snarkjs `[x, y, z]` projective triple, a
+/// `StructuredProof`/`StructuredVerifyingKey` point is already affine.
+#[cfg(feature = "production-verification")]
+fn parse_g1_affine(coords: &[String; 2]) -> Result<G1Affine, ContractError> {
+    let x = parse_fq_str(&coords[0])?;
+    let y = parse_fq_str(&coords[1])?;
+    let point = G1Affine::new_unchecked(x, y);
+    if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(ContractError::InvalidVerificationKey {});
+    }
+    Ok(point)
+}
+
+/// Affine G2 point from a `[[x_c0, x_c1], [y_c0, y_c1]]` pair of `Fq2`
+/// coordinates, each given as decimal/hex strings.
+#[cfg(feature = "production-verification")]
+fn parse_g2_affine(coords: &[[String; 2]; 2]) -> Result<G2Affine, ContractError> {
+    let x = ark_bn254::Fq2::new(parse_fq_str(&coords[0][0])?, parse_fq_str(&coords[0][1])?);
+    let y = ark_bn254::Fq2::new(parse_fq_str(&coords[1][0])?, parse_fq_str(&coords[1][1])?);
+    let point = G2Affine::new_unchecked(x, y);
+    if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(ContractError::InvalidVerificationKey {});
+    }
+    Ok(point)
+}
+
+/// Parse a base field element from a decimal or `0x`-hex string — the
+/// same two formats [`parse_fq_element`] accepts from a JSON `Value`, just
+/// taking the string directly since structured types have already done
+/// the field-shape validation.
+#[cfg(feature = "production-verification")]
+fn parse_fq_str(s: &str) -> Result<Fq, ContractError> {
+    if let Some(hex_str) = s.strip_prefix("0x") {
+        let big_int = BigUint::from_str_radix(hex_str, 16)
+            .map_err(|_| ContractError::InvalidVerificationKey {})?;
+        Ok(Fq::from_le_bytes_mod_order(&big_int.to_bytes_le()))
+    } else {
+        s.parse::<Fq>().map_err(|_| ContractError::InvalidVerificationKey {})
+    }
+}
+
+/// Parse a compressed-binary proof: `A‖B‖C`, each point arkworks
+/// compressed-affine (32 bytes for a G1 point, 64 for G2, sign bit in the
+/// coordinate's MSB). Rejects non-canonical or off-curve encodings.
+#[cfg(feature = "production-verification")]
+fn parse_compressed_proof(data: &[u8]) -> Result<Proof<Bn254>, ContractError> {
+    use ark_serialize::CanonicalDeserialize;
+    use std::io::Cursor;
+
+    let mut cursor = Cursor::new(data);
+    let a = G1Affine::deserialize_compressed(&mut cursor).map_err(|_| ContractError::InvalidProof {})?;
+    let b = G2Affine::deserialize_compressed(&mut cursor).map_err(|_| ContractError::InvalidProof {})?;
+    let c = G1Affine::deserialize_compressed(&mut cursor).map_err(|_| ContractError::InvalidProof {})?;
+
+    Ok(Proof { a, b, c })
+}
+
+/// Parse a compressed-binary verification key:
+/// `alpha_g1‖beta_g2‖gamma_g2‖delta_g2‖len(IC) as u32 BE‖IC...`, each point
+/// arkworks compressed-affine. Rejects non-canonical or off-curve encodings.
+#[cfg(feature = "production-verification")]
+fn parse_compressed_verification_key(data: &[u8]) -> Result<VerifyingKey<Bn254>, ContractError> {
+    use ark_serialize::CanonicalDeserialize;
+    use std::io::{Cursor, Read};
+
+    let mut cursor = Cursor::new(data);
+    let alpha_g1 = G1Affine::deserialize_compressed(&mut cursor).map_err(|_| ContractError::InvalidVerificationKey {})?;
+    let beta_g2 = G2Affine::deserialize_compressed(&mut cursor).map_err(|_| ContractError::InvalidVerificationKey {})?;
+    let gamma_g2 = G2Affine::deserialize_compressed(&mut cursor).map_err(|_| ContractError::InvalidVerificationKey {})?;
+    let delta_g2 = G2Affine::deserialize_compressed(&mut cursor).map_err(|_| ContractError::InvalidVerificationKey {})?;
+
+    let mut ic_len_bytes = [0u8; 4];
+    cursor.read_exact(&mut ic_len_bytes).map_err(|_| ContractError::InvalidVerificationKey {})?;
+    let ic_len = u32::from_be_bytes(ic_len_bytes) as usize;
+
+    let mut gamma_abc_g1 = Vec::with_capacity(ic_len);
+    for _ in 0..ic_len {
+        let point = G1Affine::deserialize_compressed(&mut cursor)
+            .map_err(|_| ContractError::InvalidVerificationKey {})?;
+        gamma_abc_g1.push(point);
+    }
+
+    Ok(VerifyingKey { alpha_g1, beta_g2, gamma_g2, delta_g2, gamma_abc_g1 })
+}
+
+/// Entry point mirroring [`verify_proof`] but accepting either the verbose
+/// snarkjs JSON encoding or the compact compressed-binary one (see
+/// [`ProofEncoding`]). For `Json`, `verification_key`/`proof` are UTF-8 JSON
+/// text; for `CompressedBinary` they're the raw point blobs described on
+/// [`parse_compressed_verification_key`]/[`parse_compressed_proof`].
+pub fn verify_proof_encoded(
+    verification_key: &[u8],
+    public_inputs: &[String],
+    proof: &[u8],
+    encoding: ProofEncoding,
+) -> Result<bool, ContractError> {
+    match encoding {
+        ProofEncoding::Json => {
+            let vk_str = std::str::from_utf8(verification_key)
+                .map_err(|_| ContractError::InvalidVerificationKey {})?;
+            let proof_str = std::str::from_utf8(proof)
+                .map_err(|_| ContractError::InvalidProof {})?;
+            verify_proof(vk_str, public_inputs, proof_str)
+        }
+        ProofEncoding::CompressedBinary => {
+            verify_proof_compressed(verification_key, public_inputs, proof)
+        }
+        ProofEncoding::Structured => verify_proof_structured(verification_key, public_inputs, proof),
+    }
+}
+
+/// Verify a [`StructuredProof`] against a [`StructuredVerifyingKey`], both
+/// deserialized with serde (`cosmwasm_std::from_json`) from the raw
+/// `Binary` bytes instead of hand-walking JSON or a fixed byte layout.
+fn verify_proof_structured(
+    verification_key: &[u8],
+    public_inputs: &[String],
+    proof: &[u8],
+) -> Result<bool, ContractError> {
+    let vk: StructuredVerifyingKey =
+        cosmwasm_std::from_json(verification_key).map_err(|_| ContractError::InvalidVerificationKey {})?;
+    let structured_proof: StructuredProof =
+        cosmwasm_std::from_json(proof).map_err(|_| ContractError::InvalidProof {})?;
+
+    if public_inputs.is_empty() {
+        return Err(ContractError::InvalidPublicInputs {});
+    }
+
+    #[cfg(not(feature = "production-verification"))]
+    {
+        let _ = (vk, structured_proof);
+        Ok(true)
+    }
+
+    #[cfg(feature = "production-verification")]
+    {
+        use ark_groth16::Groth16;
+        use ark_snark::SNARK;
+
+        let vk = parse_structured_verifying_key(&vk)?;
+        let groth16_proof = parse_structured_proof(&structured_proof)?;
+        let field_inputs = parse_public_inputs(public_inputs)?;
+
+        match Groth16::<Bn254>::verify(&vk, &field_inputs, &groth16_proof) {
+            Ok(valid) => Ok(valid),
+            Err(_) => Ok(false),
+        }
+    }
+}
+
+fn verify_proof_compressed(
+    verification_key: &[u8],
+    public_inputs: &[String],
+    proof: &[u8],
+) -> Result<bool, ContractError> {
+    // A compressed VK is alpha_g1(32) + beta_g2(64) + gamma_g2(64) +
+    // delta_g2(64) + len(IC) as u32(4) + at least one IC point(32).
+    if verification_key.len() < 32 + 64 * 3 + 4 + 32 {
+        return Err(ContractError::InvalidVerificationKey {});
+    }
+    // A compressed proof is A(32) + B(64) + C(32).
+    if proof.len() != 32 + 64 + 32 {
+        return Err(ContractError::InvalidProof {});
+    }
+    if public_inputs.is_empty() {
+        return Err(ContractError::InvalidPublicInputs {});
+    }
+
+    #[cfg(not(feature = "production-verification"))]
+    {
+        Ok(true)
+    }
+
+    #[cfg(feature = "production-verification")]
+    {
+        use ark_groth16::Groth16;
+        use ark_snark::SNARK;
+
+        let vk = parse_compressed_verification_key(verification_key)?;
+        let groth16_proof = parse_compressed_proof(proof)?;
+        let field_inputs = parse_public_inputs(public_inputs)?;
+
+        match Groth16::<Bn254>::verify(&vk, &field_inputs, &groth16_proof) {
+            Ok(valid) => Ok(valid),
+            Err(_) => Ok(false),
+        }
+    }
+}
+
 /// Simplified verification for testing and fallback
 fn verify_proof_simplified(
     verification_key: &str,
@@ -281,18 +853,28 @@ fn verify_proof_simplified(
     }
 }
 
-/// Validate verification key format
+/// Validate verification key format. Under the `production-verification`
+/// feature this fully parses `vk` the same way `verify_proof` does for a
+/// proof against it — rejecting any wrong protocol/curve tag, malformed
+/// point, or off-curve/wrong-subgroup point up front — so `RegisterCircuit`
+/// fails fast on a bad key instead of silently registering a circuit
+/// nothing can ever prove against. Without that feature, falls back to a
+/// length-only heuristic.
 pub fn validate_verification_key(vk: &str) -> StdResult<()> {
     if vk.is_empty() {
         return Err(StdError::generic_err("Verification key cannot be empty"));
     }
-    
+
     if vk.len() < 10 {
         return Err(StdError::generic_err("Verification key too short"));
     }
-    
-    // Accept any reasonable verification key format for testing
-    // In a real implementation, would validate the actual VK structure
+
+    #[cfg(feature = "production-verification")]
+    {
+        parse_verification_key(vk)
+            .map_err(|_| StdError::generic_err("Invalid Groth16 verification key structure"))?;
+    }
+
     Ok(())
 }
 
@@ -355,6 +937,60 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_verify_proof_encoded_json() {
+        let vk = b"vk_test_key_12345";
+        let inputs = vec!["123".to_string()];
+        let proof = br#"{"pi_a": ["0x123"], "pi_b": [["0x456"]], "pi_c": ["0x789"]}"#;
+
+        let result = verify_proof_encoded(vk, &inputs, proof, ProofEncoding::Json);
+        assert!(result.is_ok());
+        assert!(result.unwrap());
+    }
+
+    #[test]
+    fn test_verify_proof_encoded_structured() {
+        let vk = StructuredVerifyingKey {
+            alpha_g1: ["0x1".to_string(), "0x2".to_string()],
+            beta_g2: [["0x1".to_string(), "0x2".to_string()], ["0x3".to_string(), "0x4".to_string()]],
+            gamma_g2: [["0x1".to_string(), "0x2".to_string()], ["0x3".to_string(), "0x4".to_string()]],
+            delta_g2: [["0x1".to_string(), "0x2".to_string()], ["0x3".to_string(), "0x4".to_string()]],
+            ic: vec![["0x1".to_string(), "0x2".to_string()]],
+        };
+        let proof = StructuredProof {
+            pi_a: ["0x1".to_string(), "0x2".to_string()],
+            pi_b: [["0x1".to_string(), "0x2".to_string()], ["0x3".to_string(), "0x4".to_string()]],
+            pi_c: ["0x1".to_string(), "0x2".to_string()],
+        };
+        let vk_bytes = cosmwasm_std::to_json_vec(&vk).unwrap();
+        let proof_bytes = cosmwasm_std::to_json_vec(&proof).unwrap();
+        let inputs = vec!["123".to_string()];
+
+        let result = verify_proof_encoded(&vk_bytes, &inputs, &proof_bytes, ProofEncoding::Structured);
+        assert!(result.is_ok());
+        assert!(result.unwrap());
+    }
+
+    #[test]
+    fn test_verify_proof_encoded_structured_rejects_malformed_json() {
+        let vk = b"not valid json";
+        let proof = b"not valid json either";
+        let inputs = vec!["123".to_string()];
+
+        let result = verify_proof_encoded(vk, &inputs, proof, ProofEncoding::Structured);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_verify_proof_encoded_compressed_binary_rejects_short_blobs() {
+        let vk = vec![0u8; 10];
+        let proof = vec![0u8; 10];
+        let inputs = vec!["123".to_string()];
+
+        let result = verify_proof_encoded(&vk, &inputs, &proof, ProofEncoding::CompressedBinary);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_validate_verification_key() {
         assert!(validate_verification_key("vk_test_key_12345").is_ok());
@@ -362,6 +998,51 @@ mod tests {
         assert!(validate_verification_key("short").is_err());
     }
 
+    /// Under `production-verification`, a key that's long enough and
+    /// non-empty (so it'd pass the plain heuristic) but isn't valid Groth16
+    /// VK JSON must still be rejected at registration time, not just on the
+    /// first `SubmitProof` against it.
+    #[test]
+    #[cfg(feature = "production-verification")]
+    fn test_validate_verification_key_rejects_malformed_groth16_structure() {
+        let not_json = "vk_test_key_12345";
+        assert!(validate_verification_key(not_json).is_err());
+
+        let json_missing_fields = r#"{"protocol": "groth16", "curve": "bn128"}"#;
+        assert!(validate_verification_key(json_missing_fields).is_err());
+
+        let wrong_curve = r#"{"protocol": "groth16", "curve": "bls12-381", "vk_alpha_1": [], "vk_beta_2": [], "vk_gamma_2": [], "vk_delta_2": [], "IC": []}"#;
+        assert!(validate_verification_key(wrong_curve).is_err());
+    }
+
+    #[test]
+    fn test_verify_proof_batch() {
+        let vk = "vk_test_key_12345";
+        let proof = r#"{"pi_a": ["0x123"], "pi_b": [["0x456"]], "pi_c": ["0x789"]}"#.to_string();
+        let proofs = vec![
+            (vec!["123".to_string()], proof.clone()),
+            (vec!["456".to_string()], proof.clone()),
+        ];
+
+        let result = verify_proof_batch(vk, &proofs);
+        assert!(result.is_ok());
+        assert!(result.unwrap());
+    }
+
+    #[test]
+    fn test_verify_proof_batch_one_invalid_fails_whole_batch() {
+        let vk = "vk_test_key_12345";
+        let proof = r#"{"pi_a": ["0x123"], "pi_b": [["0x456"]], "pi_c": ["0x789"]}"#.to_string();
+        let proofs = vec![
+            (vec!["123".to_string()], proof.clone()),
+            (vec!["999999".to_string()], proof), // triggers failure
+        ];
+
+        let result = verify_proof_batch(vk, &proofs);
+        assert!(result.is_ok());
+        assert!(!result.unwrap());
+    }
+
     #[test]
     fn test_validate_proof() {
         let valid_proof = r#"{"pi_a": ["0x123"], "pi_b": [["0x456"]], "pi_c": ["0x789"]}"#;
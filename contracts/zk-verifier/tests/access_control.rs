@@ -1,13 +1,17 @@
 use cosmwasm_std::{
     testing::{mock_dependencies, mock_env, mock_info},
-    coins, Addr, StdError,
+    coins, Addr, BankMsg, Coin, CosmosMsg, StdError, Uint128,
 };
 use cw_multi_test::{App, AppBuilder, Contract, ContractWrapper, Executor};
 use zk_verifier::{
     contract::{execute, instantiate, query},
     error::ContractError,
-    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
-    access_control::{ADMIN_ROLE, CIRCUIT_MANAGER_ROLE, ISSUER_MANAGER_ROLE},
+    msg::{
+        ExecuteMsg, FeeConfigResponse, InstantiateMsg, ProposalResponse, QueryMsg,
+        TimelockTransactionResponse, TimelockTransactionsResponse,
+    },
+    access_control::{ADMIN_ROLE, CIRCUIT_MANAGER_ROLE, ISSUER_MANAGER_ROLE, GOVERNANCE_ROLE, PROPOSER_ROLE, EXECUTOR_ROLE},
+    state::TimelockStatus,
 };
 
 fn contract_template() -> Box<dyn Contract<cosmwasm_std::Empty>> {
@@ -37,6 +41,20 @@ fn test_role_based_access_control() {
                 multisig_config: None,
                 timelock_enabled: Some(true),
                 min_timelock_delay: Some(3600), // 1 hour
+                executor_allowlist: None,
+                registration_fee: None,
+                default_voting_power: None,
+                default_quorum_threshold: None,
+                default_pass_threshold: None,
+                default_quorum: None,
+                default_threshold: None,
+                default_timelock_grace_period: None,
+                voting_period_seconds: None,
+                min_voting_period_seconds: None,
+                proposal_deposit: None,
+                randomness_provider: None,
+                issuer_bond: None,
+                rate_limit: None,
             },
             &[],
             "ZK Verifier",
@@ -89,6 +107,11 @@ fn test_role_based_access_control() {
         circuit_id: "test_circuit".to_string(),
         verification_key: "test_vk".to_string(),
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     app.execute_contract(
@@ -104,6 +127,11 @@ fn test_role_based_access_control() {
         circuit_id: "unauthorized_circuit".to_string(),
         verification_key: "test_vk".to_string(),
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     let err = app
@@ -134,6 +162,20 @@ fn test_unauthorized_role_grant_rejection() {
                 multisig_config: None,
                 timelock_enabled: Some(false),
                 min_timelock_delay: Some(0),
+                executor_allowlist: None,
+                registration_fee: None,
+                default_voting_power: None,
+                default_quorum_threshold: None,
+                default_pass_threshold: None,
+                default_quorum: None,
+                default_threshold: None,
+                default_timelock_grace_period: None,
+                voting_period_seconds: None,
+                min_voting_period_seconds: None,
+                proposal_deposit: None,
+                randomness_provider: None,
+                issuer_bond: None,
+                rate_limit: None,
             },
             &[],
             "ZK Verifier",
@@ -176,6 +218,20 @@ fn test_timelock_functionality() {
                 multisig_config: None,
                 timelock_enabled: Some(true),
                 min_timelock_delay: Some(3600), // 1 hour
+                executor_allowlist: None,
+                registration_fee: None,
+                default_voting_power: None,
+                default_quorum_threshold: None,
+                default_pass_threshold: None,
+                default_quorum: None,
+                default_threshold: None,
+                default_timelock_grace_period: None,
+                voting_period_seconds: None,
+                min_voting_period_seconds: None,
+                proposal_deposit: None,
+                randomness_provider: None,
+                issuer_bond: None,
+                rate_limit: None,
             },
             &[],
             "ZK Verifier",
@@ -185,9 +241,10 @@ fn test_timelock_functionality() {
 
     // Test: Schedule timelock transaction
     let schedule_msg = ExecuteMsg::ScheduleTimelockTransaction {
-        target_function: "update_admin".to_string(),
-        params: r#"{"new_admin": "new_admin_address"}"#.to_string(),
+        msgs: vec![],
         delay: 7200, // 2 hours
+        executors: None,
+        grace_period: None,
     };
 
     let res = app
@@ -228,6 +285,178 @@ fn test_timelock_functionality() {
     // Note: In real tests, we would advance block time appropriately
 }
 
+#[test]
+fn test_schedule_requires_proposer_role() {
+    let mut app = App::default();
+    let code_id = app.store_code(contract_template());
+
+    let contract_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {
+                admin: Some(ADMIN.to_string()),
+                governance_enabled: Some(true),
+                dao_address: None,
+                multisig_config: None,
+                timelock_enabled: Some(true),
+                min_timelock_delay: Some(3600),
+                executor_allowlist: None,
+                registration_fee: None,
+                default_voting_power: None,
+                default_quorum_threshold: None,
+                default_pass_threshold: None,
+                default_quorum: None,
+                default_threshold: None,
+                default_timelock_grace_period: None,
+                voting_period_seconds: None,
+                min_voting_period_seconds: None,
+                proposal_deposit: None,
+                randomness_provider: None,
+                issuer_bond: None,
+                rate_limit: None,
+            },
+            &[],
+            "ZK Verifier",
+            None,
+        )
+        .unwrap();
+
+    // Test: A user without PROPOSER_ROLE cannot schedule, even with a
+    // sufficient delay.
+    let schedule_msg = ExecuteMsg::ScheduleTimelockTransaction {
+        msgs: vec![],
+        delay: 7200,
+        executors: None,
+        grace_period: None,
+    };
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER1),
+            contract_addr.clone(),
+            &schedule_msg,
+            &[],
+        )
+        .unwrap_err();
+
+    assert!(err.to_string().contains("MissingRole"));
+    assert!(err.to_string().contains("PROPOSER"));
+
+    // Test: Granting PROPOSER_ROLE lets USER1 schedule.
+    let grant_msg = ExecuteMsg::GrantRole {
+        role: PROPOSER_ROLE.to_string(),
+        account: USER1.to_string(),
+    };
+    app.execute_contract(Addr::unchecked(ADMIN), contract_addr.clone(), &grant_msg, &[])
+        .unwrap();
+
+    app.execute_contract(Addr::unchecked(USER1), contract_addr, &schedule_msg, &[])
+        .unwrap();
+}
+
+#[test]
+fn test_open_executor_fallback() {
+    let mut app = App::default();
+    let code_id = app.store_code(contract_template());
+
+    let contract_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {
+                admin: Some(ADMIN.to_string()),
+                governance_enabled: Some(true),
+                dao_address: None,
+                multisig_config: None,
+                timelock_enabled: Some(true),
+                min_timelock_delay: Some(3600),
+                executor_allowlist: None,
+                registration_fee: None,
+                default_voting_power: None,
+                default_quorum_threshold: None,
+                default_pass_threshold: None,
+                default_quorum: None,
+                default_threshold: None,
+                default_timelock_grace_period: None,
+                voting_period_seconds: None,
+                min_voting_period_seconds: None,
+                proposal_deposit: None,
+                randomness_provider: None,
+                issuer_bond: None,
+                rate_limit: None,
+            },
+            &[],
+            "ZK Verifier",
+            None,
+        )
+        .unwrap();
+
+    // Schedule with no per-tx executor allowlist (the open-execution
+    // fallback), then advance the block time past the delay.
+    let schedule_msg = ExecuteMsg::ScheduleTimelockTransaction {
+        msgs: vec![],
+        delay: 3600,
+        executors: None,
+        grace_period: None,
+    };
+
+    let res = app
+        .execute_contract(Addr::unchecked(ADMIN), contract_addr.clone(), &schedule_msg, &[])
+        .unwrap();
+
+    let transaction_id = res.events[1].attributes
+        .iter()
+        .find(|attr| attr.key == "transaction_id")
+        .unwrap()
+        .value
+        .parse::<u64>()
+        .unwrap();
+
+    app.update_block(|block| block.time = block.time.plus_seconds(3601));
+
+    // UNAUTHORIZED holds neither EXECUTOR_ROLE nor a per-tx allowlist spot,
+    // but the empty `executors` set means any address may execute.
+    let execute_msg = ExecuteMsg::ExecuteTimelockTransaction { transaction_id };
+    app.execute_contract(Addr::unchecked(UNAUTHORIZED), contract_addr.clone(), &execute_msg, &[])
+        .unwrap();
+
+    // Scheduling a second transaction with a restrictive per-tx allowlist
+    // shows the same address is rejected once one is configured.
+    let schedule_msg = ExecuteMsg::ScheduleTimelockTransaction {
+        msgs: vec![],
+        delay: 3600,
+        executors: Some(vec![ADMIN.to_string()]),
+        grace_period: None,
+    };
+
+    let res = app
+        .execute_contract(Addr::unchecked(ADMIN), contract_addr.clone(), &schedule_msg, &[])
+        .unwrap();
+
+    let transaction_id = res.events[1].attributes
+        .iter()
+        .find(|attr| attr.key == "transaction_id")
+        .unwrap()
+        .value
+        .parse::<u64>()
+        .unwrap();
+
+    app.update_block(|block| block.time = block.time.plus_seconds(3601));
+
+    let execute_msg = ExecuteMsg::ExecuteTimelockTransaction { transaction_id };
+    let err = app
+        .execute_contract(Addr::unchecked(UNAUTHORIZED), contract_addr.clone(), &execute_msg, &[])
+        .unwrap_err();
+    assert!(err.to_string().contains("MissingRole"));
+    assert!(err.to_string().contains("EXECUTOR"));
+
+    // ADMIN is in the per-tx allowlist (and also holds EXECUTOR_ROLE by
+    // default), so execution succeeds.
+    app.execute_contract(Addr::unchecked(ADMIN), contract_addr, &execute_msg, &[])
+        .unwrap();
+}
+
 #[test]
 fn test_multisig_requirements() {
     let mut app = App::default();
@@ -255,6 +484,20 @@ fn test_multisig_requirements() {
                 multisig_config: Some(multisig_config),
                 timelock_enabled: Some(true),
                 min_timelock_delay: Some(3600),
+                executor_allowlist: None,
+                registration_fee: None,
+                default_voting_power: None,
+                default_quorum_threshold: None,
+                default_pass_threshold: None,
+                default_quorum: None,
+                default_threshold: None,
+                default_timelock_grace_period: None,
+                voting_period_seconds: None,
+                min_voting_period_seconds: None,
+                proposal_deposit: None,
+                randomness_provider: None,
+                issuer_bond: None,
+                rate_limit: None,
             },
             &[],
             "ZK Verifier",
@@ -264,9 +507,10 @@ fn test_multisig_requirements() {
 
     // Schedule a timelock transaction
     let schedule_msg = ExecuteMsg::ScheduleTimelockTransaction {
-        target_function: "critical_function".to_string(),
-        params: "{}".to_string(),
+        msgs: vec![],
         delay: 3600,
+        executors: None,
+        grace_period: None,
     };
 
     let res = app
@@ -349,6 +593,20 @@ fn test_access_control_circuit_registration() {
                 multisig_config: None,
                 timelock_enabled: Some(false),
                 min_timelock_delay: Some(0),
+                executor_allowlist: None,
+                registration_fee: None,
+                default_voting_power: None,
+                default_quorum_threshold: None,
+                default_pass_threshold: None,
+                default_quorum: None,
+                default_threshold: None,
+                default_timelock_grace_period: None,
+                voting_period_seconds: None,
+                min_voting_period_seconds: None,
+                proposal_deposit: None,
+                randomness_provider: None,
+                issuer_bond: None,
+                rate_limit: None,
             },
             &[],
             "ZK Verifier",
@@ -375,6 +633,11 @@ fn test_access_control_circuit_registration() {
         circuit_id: "authorized_circuit".to_string(),
         verification_key: "test_vk".to_string(),
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     let res = app
@@ -393,6 +656,11 @@ fn test_access_control_circuit_registration() {
         circuit_id: "unauthorized_circuit".to_string(),
         verification_key: "test_vk".to_string(),
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     let err = app
@@ -424,6 +692,20 @@ fn test_role_revocation() {
                 multisig_config: None,
                 timelock_enabled: Some(false),
                 min_timelock_delay: Some(0),
+                executor_allowlist: None,
+                registration_fee: None,
+                default_voting_power: None,
+                default_quorum_threshold: None,
+                default_pass_threshold: None,
+                default_quorum: None,
+                default_threshold: None,
+                default_timelock_grace_period: None,
+                voting_period_seconds: None,
+                min_voting_period_seconds: None,
+                proposal_deposit: None,
+                randomness_provider: None,
+                issuer_bond: None,
+                rate_limit: None,
             },
             &[],
             "ZK Verifier",
@@ -490,6 +772,11 @@ fn test_role_revocation() {
         circuit_id: "revoked_user_circuit".to_string(),
         verification_key: "test_vk".to_string(),
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     let err = app
@@ -520,6 +807,20 @@ fn test_least_privilege_principle() {
                 multisig_config: None,
                 timelock_enabled: Some(false),
                 min_timelock_delay: Some(0),
+                executor_allowlist: None,
+                registration_fee: None,
+                default_voting_power: None,
+                default_quorum_threshold: None,
+                default_pass_threshold: None,
+                default_quorum: None,
+                default_threshold: None,
+                default_timelock_grace_period: None,
+                voting_period_seconds: None,
+                min_voting_period_seconds: None,
+                proposal_deposit: None,
+                randomness_provider: None,
+                issuer_bond: None,
+                rate_limit: None,
             },
             &[],
             "ZK Verifier",
@@ -545,6 +846,7 @@ fn test_least_privilege_principle() {
     let add_issuer_msg = ExecuteMsg::AddIssuer {
         issuer_address: "some_issuer".to_string(),
         authorized_circuits: vec!["groth16".to_string()],
+        expires_at: None,
     };
 
     let err = app
@@ -577,5 +879,1600 @@ fn test_least_privilege_principle() {
     assert!(err.to_string().contains("ADMIN"));
 }
 
-// Mock imports for test compilation
-use zk_verifier::state::MultisigConfig;
\ No newline at end of file
+#[test]
+fn test_delegated_role_admin() {
+    let mut app = App::default();
+    let code_id = app.store_code(contract_template());
+
+    let contract_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {
+                admin: Some(ADMIN.to_string()),
+                governance_enabled: Some(false),
+                dao_address: None,
+                multisig_config: None,
+                timelock_enabled: Some(false),
+                min_timelock_delay: Some(0),
+                executor_allowlist: None,
+                registration_fee: None,
+                default_voting_power: None,
+                default_quorum_threshold: None,
+                default_pass_threshold: None,
+                default_quorum: None,
+                default_threshold: None,
+                default_timelock_grace_period: None,
+                voting_period_seconds: None,
+                min_voting_period_seconds: None,
+                proposal_deposit: None,
+                randomness_provider: None,
+                issuer_bond: None,
+                rate_limit: None,
+            },
+            &[],
+            "ZK Verifier",
+            None,
+        )
+        .unwrap();
+
+    // New roles default to ADMIN_ROLE as their admin.
+    let role_admin: String = app
+        .wrap()
+        .query_wasm_smart(
+            contract_addr.clone(),
+            &QueryMsg::RoleAdmin { role: ISSUER_MANAGER_ROLE.to_string() },
+        )
+        .unwrap();
+    assert_eq!(role_admin, ADMIN_ROLE);
+
+    // Grant USER1 CIRCUIT_MANAGER_ROLE, then delegate ISSUER_MANAGER_ROLE
+    // administration to CIRCUIT_MANAGER_ROLE.
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contract_addr.clone(),
+        &ExecuteMsg::GrantRole {
+            role: CIRCUIT_MANAGER_ROLE.to_string(),
+            account: USER1.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contract_addr.clone(),
+        &ExecuteMsg::SetRoleAdmin {
+            role: ISSUER_MANAGER_ROLE.to_string(),
+            admin_role: CIRCUIT_MANAGER_ROLE.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let role_admin: String = app
+        .wrap()
+        .query_wasm_smart(
+            contract_addr.clone(),
+            &QueryMsg::RoleAdmin { role: ISSUER_MANAGER_ROLE.to_string() },
+        )
+        .unwrap();
+    assert_eq!(role_admin, CIRCUIT_MANAGER_ROLE);
+
+    // USER1, holding only CIRCUIT_MANAGER_ROLE, can now grant/revoke
+    // ISSUER_MANAGER_ROLE without holding ADMIN_ROLE.
+    app.execute_contract(
+        Addr::unchecked(USER1),
+        contract_addr.clone(),
+        &ExecuteMsg::GrantRole {
+            role: ISSUER_MANAGER_ROLE.to_string(),
+            account: USER2.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let has_role: bool = app
+        .wrap()
+        .query_wasm_smart(
+            contract_addr.clone(),
+            &QueryMsg::HasRole { role: ISSUER_MANAGER_ROLE.to_string(), account: USER2.to_string() },
+        )
+        .unwrap();
+    assert!(has_role);
+
+    app.execute_contract(
+        Addr::unchecked(USER1),
+        contract_addr.clone(),
+        &ExecuteMsg::RevokeRole {
+            role: ISSUER_MANAGER_ROLE.to_string(),
+            account: USER2.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // USER1 still cannot administer a role it wasn't delegated, e.g.
+    // GOVERNANCE_ROLE remains ADMIN_ROLE-gated.
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER1),
+            contract_addr.clone(),
+            &ExecuteMsg::GrantRole {
+                role: GOVERNANCE_ROLE.to_string(),
+                account: USER2.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("MissingRole"));
+
+    // Nor can USER1 re-point its own role's admin upward: CIRCUIT_MANAGER_ROLE
+    // is still administered by ADMIN_ROLE, which USER1 does not hold.
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER1),
+            contract_addr.clone(),
+            &ExecuteMsg::SetRoleAdmin {
+                role: CIRCUIT_MANAGER_ROLE.to_string(),
+                admin_role: CIRCUIT_MANAGER_ROLE.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("MissingRole"));
+    assert!(err.to_string().contains("ADMIN"));
+}
+
+#[test]
+fn test_freeze_timelock_blocks_reconfiguration_but_not_scheduling() {
+    let mut app = App::default();
+    let code_id = app.store_code(contract_template());
+
+    let contract_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {
+                admin: Some(ADMIN.to_string()),
+                governance_enabled: Some(true),
+                dao_address: None,
+                multisig_config: None,
+                timelock_enabled: Some(true),
+                min_timelock_delay: Some(3600),
+                executor_allowlist: None,
+                registration_fee: None,
+                default_voting_power: None,
+                default_quorum_threshold: None,
+                default_pass_threshold: None,
+                default_quorum: None,
+                default_threshold: None,
+                default_timelock_grace_period: None,
+                voting_period_seconds: None,
+                min_voting_period_seconds: None,
+                proposal_deposit: None,
+                randomness_provider: None,
+                issuer_bond: None,
+                rate_limit: None,
+            },
+            &[],
+            "ZK Verifier",
+            None,
+        )
+        .unwrap();
+
+    // Non-admin cannot freeze.
+    let err = app
+        .execute_contract(Addr::unchecked(USER1), contract_addr.clone(), &ExecuteMsg::FreezeTimelock {}, &[])
+        .unwrap_err();
+    assert!(err.to_string().contains("MissingRole"));
+
+    // Admin freezes the governance configuration.
+    app.execute_contract(Addr::unchecked(ADMIN), contract_addr.clone(), &ExecuteMsg::FreezeTimelock {}, &[])
+        .unwrap();
+
+    let governance_config: zk_verifier::msg::GovernanceConfigResponse = app
+        .wrap()
+        .query_wasm_smart(contract_addr.clone(), &QueryMsg::GovernanceConfig {})
+        .unwrap();
+    assert!(governance_config.frozen);
+
+    // Post-freeze, granting/revoking PROPOSER_ROLE or EXECUTOR_ROLE fails.
+    let grant_msg = ExecuteMsg::GrantRole {
+        role: PROPOSER_ROLE.to_string(),
+        account: USER1.to_string(),
+    };
+    let err = app
+        .execute_contract(Addr::unchecked(ADMIN), contract_addr.clone(), &grant_msg, &[])
+        .unwrap_err();
+    assert!(err.to_string().contains("TimelockFrozen"));
+
+    let revoke_msg = ExecuteMsg::RevokeRole {
+        role: EXECUTOR_ROLE.to_string(),
+        account: ADMIN.to_string(),
+    };
+    let err = app
+        .execute_contract(Addr::unchecked(ADMIN), contract_addr.clone(), &revoke_msg, &[])
+        .unwrap_err();
+    assert!(err.to_string().contains("TimelockFrozen"));
+
+    // The freeze covers all access-control reconfiguration, not just
+    // PROPOSER_ROLE/EXECUTOR_ROLE: granting an unrelated role fails too.
+    let grant_circuit_manager_msg = ExecuteMsg::GrantRole {
+        role: CIRCUIT_MANAGER_ROLE.to_string(),
+        account: USER1.to_string(),
+    };
+    let err = app
+        .execute_contract(Addr::unchecked(ADMIN), contract_addr.clone(), &grant_circuit_manager_msg, &[])
+        .unwrap_err();
+    assert!(err.to_string().contains("TimelockFrozen"));
+
+    // Re-pointing a role's admin is blocked too.
+    let set_role_admin_msg = ExecuteMsg::SetRoleAdmin {
+        role: CIRCUIT_MANAGER_ROLE.to_string(),
+        admin_role: GOVERNANCE_ROLE.to_string(),
+    };
+    let err = app
+        .execute_contract(Addr::unchecked(ADMIN), contract_addr.clone(), &set_role_admin_msg, &[])
+        .unwrap_err();
+    assert!(err.to_string().contains("TimelockFrozen"));
+
+    // As is admin transfer.
+    let update_admin_msg = ExecuteMsg::UpdateAdmin {
+        new_admin: USER1.to_string(),
+    };
+    let err = app
+        .execute_contract(Addr::unchecked(ADMIN), contract_addr.clone(), &update_admin_msg, &[])
+        .unwrap_err();
+    assert!(err.to_string().contains("TimelockFrozen"));
+
+    // Scheduling (ADMIN already holds PROPOSER_ROLE) still works post-freeze.
+    let schedule_msg = ExecuteMsg::ScheduleTimelockTransaction {
+        msgs: vec![],
+        delay: 3600,
+        executors: None,
+        grace_period: None,
+    };
+    app.execute_contract(Addr::unchecked(ADMIN), contract_addr, &schedule_msg, &[])
+        .unwrap();
+}
+
+#[test]
+fn test_role_enumeration_queries() {
+    let mut app = App::default();
+    let code_id = app.store_code(contract_template());
+
+    let contract_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {
+                admin: Some(ADMIN.to_string()),
+                governance_enabled: Some(false),
+                dao_address: None,
+                multisig_config: None,
+                timelock_enabled: Some(false),
+                min_timelock_delay: Some(0),
+                executor_allowlist: None,
+                registration_fee: None,
+                default_voting_power: None,
+                default_quorum_threshold: None,
+                default_pass_threshold: None,
+                default_quorum: None,
+                default_threshold: None,
+                default_timelock_grace_period: None,
+                voting_period_seconds: None,
+                min_voting_period_seconds: None,
+                proposal_deposit: None,
+                randomness_provider: None,
+                issuer_bond: None,
+                rate_limit: None,
+            },
+            &[],
+            "ZK Verifier",
+            None,
+        )
+        .unwrap();
+
+    // ListRoles includes ADMIN_ROLE (ADMIN is a member by default) but not
+    // CIRCUIT_MANAGER_ROLE yet (no members).
+    let roles: Vec<String> = app
+        .wrap()
+        .query_wasm_smart(contract_addr.clone(), &QueryMsg::ListRoles {})
+        .unwrap();
+    assert!(roles.contains(&ADMIN_ROLE.to_string()));
+    assert!(!roles.contains(&CIRCUIT_MANAGER_ROLE.to_string()));
+
+    let count: u64 = app
+        .wrap()
+        .query_wasm_smart(
+            contract_addr.clone(),
+            &QueryMsg::RoleMemberCount { role: CIRCUIT_MANAGER_ROLE.to_string() },
+        )
+        .unwrap();
+    assert_eq!(count, 0);
+
+    // Grant CIRCUIT_MANAGER_ROLE to USER1 and USER2.
+    for user in [USER1, USER2] {
+        app.execute_contract(
+            Addr::unchecked(ADMIN),
+            contract_addr.clone(),
+            &ExecuteMsg::GrantRole {
+                role: CIRCUIT_MANAGER_ROLE.to_string(),
+                account: user.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let count: u64 = app
+        .wrap()
+        .query_wasm_smart(
+            contract_addr.clone(),
+            &QueryMsg::RoleMemberCount { role: CIRCUIT_MANAGER_ROLE.to_string() },
+        )
+        .unwrap();
+    assert_eq!(count, 2);
+
+    let members: Vec<Addr> = app
+        .wrap()
+        .query_wasm_smart(
+            contract_addr.clone(),
+            &QueryMsg::RoleMembers {
+                role: CIRCUIT_MANAGER_ROLE.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(members, vec![Addr::unchecked(USER1), Addr::unchecked(USER2)]);
+
+    // Paginate with limit 1, then continue from start_after.
+    let first_page: Vec<Addr> = app
+        .wrap()
+        .query_wasm_smart(
+            contract_addr.clone(),
+            &QueryMsg::RoleMembers {
+                role: CIRCUIT_MANAGER_ROLE.to_string(),
+                start_after: None,
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+    assert_eq!(first_page, vec![Addr::unchecked(USER1)]);
+
+    let second_page: Vec<Addr> = app
+        .wrap()
+        .query_wasm_smart(
+            contract_addr.clone(),
+            &QueryMsg::RoleMembers {
+                role: CIRCUIT_MANAGER_ROLE.to_string(),
+                start_after: Some(USER1.to_string()),
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+    assert_eq!(second_page, vec![Addr::unchecked(USER2)]);
+
+    let roles: Vec<String> = app
+        .wrap()
+        .query_wasm_smart(contract_addr.clone(), &QueryMsg::ListRoles {})
+        .unwrap();
+    assert!(roles.contains(&CIRCUIT_MANAGER_ROLE.to_string()));
+
+    // Revoke USER1 and re-check the count and membership.
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contract_addr.clone(),
+        &ExecuteMsg::RevokeRole {
+            role: CIRCUIT_MANAGER_ROLE.to_string(),
+            account: USER1.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let count: u64 = app
+        .wrap()
+        .query_wasm_smart(
+            contract_addr.clone(),
+            &QueryMsg::RoleMemberCount { role: CIRCUIT_MANAGER_ROLE.to_string() },
+        )
+        .unwrap();
+    assert_eq!(count, 1);
+
+    let members: Vec<Addr> = app
+        .wrap()
+        .query_wasm_smart(
+            contract_addr,
+            &QueryMsg::RoleMembers {
+                role: CIRCUIT_MANAGER_ROLE.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(members, vec![Addr::unchecked(USER2)]);
+}
+
+// Mock imports for test compilation
+use zk_verifier::state::{MultisigConfig, ProposalType};
+
+const FEE_DENOM: &str = "uatom";
+
+fn mock_app_with_funds(funded: &[&str]) -> App {
+    AppBuilder::new().build(|router, _, storage| {
+        for addr in funded {
+            router
+                .bank
+                .init_balance(
+                    storage,
+                    &Addr::unchecked(*addr),
+                    coins(1_000_000, FEE_DENOM),
+                )
+                .unwrap();
+        }
+    })
+}
+
+#[test]
+fn test_registration_fee_rejects_underfunded() {
+    let mut app = mock_app_with_funds(&[ADMIN, USER1]);
+    let code_id = app.store_code(contract_template());
+
+    let contract_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {
+                admin: Some(ADMIN.to_string()),
+                governance_enabled: Some(false),
+                dao_address: None,
+                multisig_config: None,
+                timelock_enabled: Some(false),
+                min_timelock_delay: Some(0),
+                executor_allowlist: None,
+                registration_fee: Some(Coin { denom: FEE_DENOM.to_string(), amount: Uint128::new(1000) }),
+                default_voting_power: None,
+                default_quorum_threshold: None,
+                default_pass_threshold: None,
+                default_quorum: None,
+                default_threshold: None,
+                default_timelock_grace_period: None,
+                voting_period_seconds: None,
+                min_voting_period_seconds: None,
+                proposal_deposit: None,
+                randomness_provider: None,
+                issuer_bond: None,
+                rate_limit: None,
+            },
+            &[],
+            "ZK Verifier",
+            None,
+        )
+        .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contract_addr.clone(),
+        &ExecuteMsg::GrantRole {
+            role: CIRCUIT_MANAGER_ROLE.to_string(),
+            account: USER1.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let register_msg = ExecuteMsg::RegisterCircuit {
+        circuit_id: "fee_circuit".to_string(),
+        verification_key: "test_vk".to_string(),
+        circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
+    };
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER1),
+            contract_addr.clone(),
+            &register_msg,
+            &coins(500, FEE_DENOM),
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("Insufficient fee"));
+}
+
+#[test]
+fn test_registration_fee_exact_amount_succeeds_and_is_withdrawable() {
+    let mut app = mock_app_with_funds(&[ADMIN, USER1]);
+    let code_id = app.store_code(contract_template());
+
+    let contract_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {
+                admin: Some(ADMIN.to_string()),
+                governance_enabled: Some(false),
+                dao_address: None,
+                multisig_config: None,
+                timelock_enabled: Some(false),
+                min_timelock_delay: Some(0),
+                executor_allowlist: None,
+                registration_fee: Some(Coin { denom: FEE_DENOM.to_string(), amount: Uint128::new(1000) }),
+                default_voting_power: None,
+                default_quorum_threshold: None,
+                default_pass_threshold: None,
+                default_quorum: None,
+                default_threshold: None,
+                default_timelock_grace_period: None,
+                voting_period_seconds: None,
+                min_voting_period_seconds: None,
+                proposal_deposit: None,
+                randomness_provider: None,
+                issuer_bond: None,
+                rate_limit: None,
+            },
+            &[],
+            "ZK Verifier",
+            None,
+        )
+        .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contract_addr.clone(),
+        &ExecuteMsg::GrantRole {
+            role: CIRCUIT_MANAGER_ROLE.to_string(),
+            account: USER1.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let register_msg = ExecuteMsg::RegisterCircuit {
+        circuit_id: "fee_circuit".to_string(),
+        verification_key: "test_vk".to_string(),
+        circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
+    };
+
+    app.execute_contract(
+        Addr::unchecked(USER1),
+        contract_addr.clone(),
+        &register_msg,
+        &coins(1000, FEE_DENOM),
+    )
+    .unwrap();
+
+    let collected: Vec<Coin> = app
+        .wrap()
+        .query_wasm_smart(contract_addr.clone(), &QueryMsg::CollectedFees {})
+        .unwrap();
+    assert_eq!(collected, vec![Coin { denom: FEE_DENOM.to_string(), amount: Uint128::new(1000) }]);
+
+    // Non-admin cannot withdraw.
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER1),
+            contract_addr.clone(),
+            &ExecuteMsg::WithdrawFees {
+                recipient: USER1.to_string(),
+                amount: Coin { denom: FEE_DENOM.to_string(), amount: Uint128::new(1000) },
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("MissingRole"));
+
+    // Admin withdrawal transfers the collected balance.
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contract_addr.clone(),
+        &ExecuteMsg::WithdrawFees {
+            recipient: ADMIN.to_string(),
+            amount: Coin { denom: FEE_DENOM.to_string(), amount: Uint128::new(1000) },
+        },
+        &[],
+    )
+    .unwrap();
+
+    let collected: Vec<Coin> = app
+        .wrap()
+        .query_wasm_smart(contract_addr, &QueryMsg::CollectedFees {})
+        .unwrap();
+    assert!(collected.is_empty());
+}
+
+#[test]
+fn test_update_fees_and_donate() {
+    let mut app = mock_app_with_funds(&[ADMIN, USER1]);
+    let code_id = app.store_code(contract_template());
+
+    let contract_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {
+                admin: Some(ADMIN.to_string()),
+                governance_enabled: Some(false),
+                dao_address: None,
+                multisig_config: None,
+                timelock_enabled: Some(false),
+                min_timelock_delay: Some(0),
+                executor_allowlist: None,
+                registration_fee: None,
+                default_voting_power: None,
+                default_quorum_threshold: None,
+                default_pass_threshold: None,
+                default_quorum: None,
+                default_threshold: None,
+                default_timelock_grace_period: None,
+                voting_period_seconds: None,
+                min_voting_period_seconds: None,
+                proposal_deposit: None,
+                randomness_provider: None,
+                issuer_bond: None,
+                rate_limit: None,
+            },
+            &[],
+            "ZK Verifier",
+            None,
+        )
+        .unwrap();
+
+    let fee_config: FeeConfigResponse = app
+        .wrap()
+        .query_wasm_smart(contract_addr.clone(), &QueryMsg::FeeConfig {})
+        .unwrap();
+    assert_eq!(fee_config.registration_fee, None);
+
+    // Non-admin cannot update fees.
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER1),
+            contract_addr.clone(),
+            &ExecuteMsg::UpdateFees {
+                registration_fee: Some(Coin { denom: FEE_DENOM.to_string(), amount: Uint128::new(1000) }),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("MissingRole"));
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contract_addr.clone(),
+        &ExecuteMsg::UpdateFees {
+            registration_fee: Some(Coin { denom: FEE_DENOM.to_string(), amount: Uint128::new(1000) }),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let fee_config: FeeConfigResponse = app
+        .wrap()
+        .query_wasm_smart(contract_addr.clone(), &QueryMsg::FeeConfig {})
+        .unwrap();
+    assert_eq!(
+        fee_config.registration_fee,
+        Some(Coin { denom: FEE_DENOM.to_string(), amount: Uint128::new(1000) })
+    );
+
+    // Anyone can donate, and donated funds land in COLLECTED_FEES.
+    app.execute_contract(
+        Addr::unchecked(USER1),
+        contract_addr.clone(),
+        &ExecuteMsg::Donate {},
+        &coins(250, FEE_DENOM),
+    )
+    .unwrap();
+
+    let collected: Vec<Coin> = app
+        .wrap()
+        .query_wasm_smart(contract_addr, &QueryMsg::CollectedFees {})
+        .unwrap();
+    assert_eq!(collected, vec![Coin { denom: FEE_DENOM.to_string(), amount: Uint128::new(250) }]);
+}
+
+#[test]
+fn test_submission_fee_rejects_underpayment_and_refunds_overpayment() {
+    let mut app = mock_app_with_funds(&[ADMIN, USER1]);
+    let code_id = app.store_code(contract_template());
+
+    let contract_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {
+                admin: Some(ADMIN.to_string()),
+                governance_enabled: Some(false),
+                dao_address: None,
+                multisig_config: None,
+                timelock_enabled: Some(false),
+                min_timelock_delay: Some(0),
+                executor_allowlist: None,
+                registration_fee: None,
+                default_voting_power: None,
+                default_quorum_threshold: None,
+                default_pass_threshold: None,
+                default_quorum: None,
+                default_threshold: None,
+                default_timelock_grace_period: None,
+                voting_period_seconds: None,
+                min_voting_period_seconds: None,
+                proposal_deposit: None,
+                randomness_provider: None,
+                issuer_bond: None,
+                rate_limit: None,
+            },
+            &[],
+            "ZK Verifier",
+            None,
+        )
+        .unwrap();
+
+    let register_msg = ExecuteMsg::RegisterCircuit {
+        circuit_id: "metered_circuit".to_string(),
+        verification_key: "test_vk".to_string(),
+        circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
+    };
+    app.execute_contract(Addr::unchecked(ADMIN), contract_addr.clone(), &register_msg, &[])
+        .unwrap();
+
+    // Only CIRCUIT_MANAGER_ROLE may set the fee.
+    let set_fee_msg = ExecuteMsg::SetCircuitSubmissionFee {
+        circuit_id: "metered_circuit".to_string(),
+        fee: Some(Coin { denom: FEE_DENOM.to_string(), amount: Uint128::new(100) }),
+    };
+    let err = app
+        .execute_contract(Addr::unchecked(USER1), contract_addr.clone(), &set_fee_msg, &[])
+        .unwrap_err();
+    assert!(err.to_string().contains("MissingRole"));
+
+    app.execute_contract(Addr::unchecked(ADMIN), contract_addr.clone(), &set_fee_msg, &[])
+        .unwrap();
+
+    let submit_msg = ExecuteMsg::SubmitProof {
+        circuit_id: "metered_circuit".to_string(),
+        public_inputs: vec!["1".to_string()],
+        proof: r#"{"pi_a": ["0x123"], "pi_b": [["0x456"]], "pi_c": ["0x789"]}"#.to_string(),
+    };
+
+    // Underpaying is rejected.
+    let err = app
+        .execute_contract(Addr::unchecked(USER1), contract_addr.clone(), &submit_msg, &coins(50, FEE_DENOM))
+        .unwrap_err();
+    assert!(err.to_string().contains("Insufficient fee"));
+
+    // Overpaying is accepted: only the fee amount is collected, and the
+    // excess is refunded back to the sender.
+    let balance_before = app.wrap().query_balance(USER1, FEE_DENOM).unwrap().amount;
+    app.execute_contract(Addr::unchecked(USER1), contract_addr.clone(), &submit_msg, &coins(150, FEE_DENOM))
+        .unwrap();
+    let balance_after = app.wrap().query_balance(USER1, FEE_DENOM).unwrap().amount;
+    assert_eq!(balance_before - balance_after, Uint128::new(100));
+
+    let collected: Vec<Coin> = app
+        .wrap()
+        .query_wasm_smart(contract_addr, &QueryMsg::CollectedFees {})
+        .unwrap();
+    assert_eq!(collected, vec![Coin { denom: FEE_DENOM.to_string(), amount: Uint128::new(100) }]);
+}
+
+#[test]
+fn test_claim_rewards_splits_collected_fees_among_role_members() {
+    let mut app = mock_app_with_funds(&[ADMIN, USER1]);
+    let code_id = app.store_code(contract_template());
+
+    let contract_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {
+                admin: Some(ADMIN.to_string()),
+                governance_enabled: Some(false),
+                dao_address: None,
+                multisig_config: None,
+                timelock_enabled: Some(false),
+                min_timelock_delay: Some(0),
+                executor_allowlist: None,
+                registration_fee: None,
+                default_voting_power: None,
+                default_quorum_threshold: None,
+                default_pass_threshold: None,
+                default_quorum: None,
+                default_threshold: None,
+                default_timelock_grace_period: None,
+                voting_period_seconds: None,
+                min_voting_period_seconds: None,
+                proposal_deposit: None,
+                randomness_provider: None,
+                issuer_bond: None,
+                rate_limit: None,
+            },
+            &[],
+            "ZK Verifier",
+            None,
+        )
+        .unwrap();
+
+    // GOVERNANCE_ROLE has no members yet, so GrantRole with itself as
+    // admin is used to seed two of them.
+    for member in [ADMIN, USER1] {
+        app.execute_contract(
+            Addr::unchecked(ADMIN),
+            contract_addr.clone(),
+            &ExecuteMsg::GrantRole { role: GOVERNANCE_ROLE.to_string(), account: member.to_string() },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let register_msg = ExecuteMsg::RegisterCircuit {
+        circuit_id: "metered_circuit".to_string(),
+        verification_key: "test_vk".to_string(),
+        circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
+    };
+    app.execute_contract(Addr::unchecked(ADMIN), contract_addr.clone(), &register_msg, &[])
+        .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contract_addr.clone(),
+        &ExecuteMsg::SetCircuitSubmissionFee {
+            circuit_id: "metered_circuit".to_string(),
+            fee: Some(Coin { denom: FEE_DENOM.to_string(), amount: Uint128::new(101) }),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let submit_msg = ExecuteMsg::SubmitProof {
+        circuit_id: "metered_circuit".to_string(),
+        public_inputs: vec!["1".to_string()],
+        proof: r#"{"pi_a": ["0x123"], "pi_b": [["0x456"]], "pi_c": ["0x789"]}"#.to_string(),
+    };
+    app.execute_contract(Addr::unchecked(USER1), contract_addr.clone(), &submit_msg, &coins(101, FEE_DENOM))
+        .unwrap();
+
+    // 101 split two ways is 50 each, with a 1-unit remainder left behind.
+    app.execute_contract(
+        Addr::unchecked(USER1),
+        contract_addr.clone(),
+        &ExecuteMsg::ClaimRewards { denom: FEE_DENOM.to_string() },
+        &[],
+    )
+    .unwrap();
+
+    let admin_balance = app.wrap().query_balance(ADMIN, FEE_DENOM).unwrap();
+    assert_eq!(admin_balance.amount, Uint128::new(1_000_000 - 101 + 50));
+    let user1_balance = app.wrap().query_balance(USER1, FEE_DENOM).unwrap();
+    assert_eq!(user1_balance.amount, Uint128::new(1_000_000 - 101 + 50));
+
+    let collected: Vec<Coin> = app
+        .wrap()
+        .query_wasm_smart(contract_addr.clone(), &QueryMsg::CollectedFees {})
+        .unwrap();
+    assert_eq!(collected, vec![Coin { denom: FEE_DENOM.to_string(), amount: Uint128::new(1) }]);
+
+    // Claiming again with nothing new collected (beyond the 1-unit dust,
+    // which can't be split among 2 recipients) fails cleanly.
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER1),
+            contract_addr,
+            &ExecuteMsg::ClaimRewards { denom: FEE_DENOM.to_string() },
+            &[],
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("NoFeesToClaim") || err.to_string().contains("fees collected"));
+}
+
+#[test]
+fn execute_timelock_transaction_dispatches_stored_cosmos_msgs() {
+    let mut app = mock_app_with_funds(&[ADMIN]);
+    let code_id = app.store_code(contract_template());
+
+    let contract_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {
+                admin: Some(ADMIN.to_string()),
+                governance_enabled: Some(true),
+                dao_address: None,
+                multisig_config: None,
+                timelock_enabled: Some(true),
+                min_timelock_delay: Some(3600),
+                executor_allowlist: None,
+                registration_fee: None,
+                default_voting_power: None,
+                default_quorum_threshold: None,
+                default_pass_threshold: None,
+                default_quorum: None,
+                default_threshold: None,
+                default_timelock_grace_period: None,
+                voting_period_seconds: None,
+                min_voting_period_seconds: None,
+                proposal_deposit: None,
+                randomness_provider: None,
+                issuer_bond: None,
+                rate_limit: None,
+            },
+            &[],
+            "ZK Verifier",
+            None,
+        )
+        .unwrap();
+
+    // Fund the contract itself so the scheduled BankMsg::Send has
+    // something real to dispatch.
+    app.send_tokens(Addr::unchecked(ADMIN), contract_addr.clone(), &coins(5_000, FEE_DENOM))
+        .unwrap();
+
+    let payout = CosmosMsg::Bank(BankMsg::Send {
+        to_address: USER1.to_string(),
+        amount: coins(5_000, FEE_DENOM),
+    });
+
+    let schedule_msg = ExecuteMsg::ScheduleTimelockTransaction {
+        msgs: vec![payout],
+        delay: 3600,
+        executors: None,
+        grace_period: None,
+    };
+    let res = app
+        .execute_contract(Addr::unchecked(ADMIN), contract_addr.clone(), &schedule_msg, &[])
+        .unwrap();
+    let transaction_id = res.events[1].attributes
+        .iter()
+        .find(|attr| attr.key == "transaction_id")
+        .unwrap()
+        .value
+        .parse::<u64>()
+        .unwrap();
+
+    app.update_block(|block| block.time = block.time.plus_seconds(3601));
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contract_addr.clone(),
+        &ExecuteMsg::ExecuteTimelockTransaction { transaction_id },
+        &[],
+    )
+    .unwrap();
+
+    // The stored BankMsg::Send was genuinely dispatched, not just marked
+    // executed: the balance moved from the contract to USER1.
+    let recipient_balance = app.wrap().query_balance(USER1, FEE_DENOM).unwrap();
+    assert_eq!(recipient_balance.amount, Uint128::new(5_000));
+    let contract_balance = app.wrap().query_balance(&contract_addr, FEE_DENOM).unwrap();
+    assert_eq!(contract_balance.amount, Uint128::zero());
+
+    // Replaying the same transaction id is still rejected (the `executed`
+    // guard), and doesn't move funds a second time.
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            contract_addr,
+            &ExecuteMsg::ExecuteTimelockTransaction { transaction_id },
+            &[],
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("TimelockAlreadyExecuted"));
+}
+
+#[test]
+fn timelock_transaction_query_reports_ready_then_expired() {
+    let mut app = App::default();
+    let code_id = app.store_code(contract_template());
+
+    let contract_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {
+                admin: Some(ADMIN.to_string()),
+                governance_enabled: Some(true),
+                dao_address: None,
+                multisig_config: None,
+                timelock_enabled: Some(true),
+                min_timelock_delay: Some(3600),
+                executor_allowlist: None,
+                registration_fee: None,
+                default_voting_power: None,
+                default_quorum_threshold: None,
+                default_pass_threshold: None,
+                default_quorum: None,
+                default_threshold: None,
+                default_timelock_grace_period: None,
+                voting_period_seconds: None,
+                min_voting_period_seconds: None,
+                proposal_deposit: None,
+                randomness_provider: None,
+                issuer_bond: None,
+                rate_limit: None,
+            },
+            &[],
+            "ZK Verifier",
+            None,
+        )
+        .unwrap();
+
+    let schedule_msg = ExecuteMsg::ScheduleTimelockTransaction {
+        msgs: vec![],
+        delay: 3600,
+        executors: None,
+        grace_period: Some(1800),
+    };
+    let res = app
+        .execute_contract(Addr::unchecked(ADMIN), contract_addr.clone(), &schedule_msg, &[])
+        .unwrap();
+    let transaction_id = res.events[1].attributes
+        .iter()
+        .find(|attr| attr.key == "transaction_id")
+        .unwrap()
+        .value
+        .parse::<u64>()
+        .unwrap();
+
+    // Before scheduled_time, the query reports Pending.
+    let tx: TimelockTransactionResponse = app
+        .wrap()
+        .query_wasm_smart(contract_addr.clone(), &QueryMsg::TimelockTransaction { transaction_id })
+        .unwrap();
+    assert_eq!(tx.status, TimelockStatus::Pending);
+
+    // Past scheduled_time but still inside the grace period, it's Ready.
+    app.update_block(|block| block.time = block.time.plus_seconds(3601));
+    let tx: TimelockTransactionResponse = app
+        .wrap()
+        .query_wasm_smart(contract_addr.clone(), &QueryMsg::TimelockTransaction { transaction_id })
+        .unwrap();
+    assert_eq!(tx.status, TimelockStatus::Ready);
+
+    // Past scheduled_time + grace_period, it's Expired, and execution is
+    // rejected with ContractError::TimelockExpired rather than firing.
+    app.update_block(|block| block.time = block.time.plus_seconds(1800));
+    let tx: TimelockTransactionResponse = app
+        .wrap()
+        .query_wasm_smart(contract_addr.clone(), &QueryMsg::TimelockTransaction { transaction_id })
+        .unwrap();
+    assert_eq!(tx.status, TimelockStatus::Expired);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            contract_addr,
+            &ExecuteMsg::ExecuteTimelockTransaction { transaction_id },
+            &[],
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("TimelockExpired"));
+}
+
+#[test]
+fn test_cancel_timelock_transaction_and_list_query() {
+    let mut app = App::default();
+    let code_id = app.store_code(contract_template());
+
+    let contract_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {
+                admin: Some(ADMIN.to_string()),
+                governance_enabled: Some(true),
+                dao_address: None,
+                multisig_config: None,
+                timelock_enabled: Some(true),
+                min_timelock_delay: Some(3600),
+                executor_allowlist: None,
+                registration_fee: None,
+                default_voting_power: None,
+                default_quorum_threshold: None,
+                default_pass_threshold: None,
+                default_quorum: None,
+                default_threshold: None,
+                default_timelock_grace_period: None,
+                voting_period_seconds: None,
+                min_voting_period_seconds: None,
+                proposal_deposit: None,
+                randomness_provider: None,
+                issuer_bond: None,
+                rate_limit: None,
+            },
+            &[],
+            "ZK Verifier",
+            None,
+        )
+        .unwrap();
+
+    let schedule = |app: &mut App| -> u64 {
+        let res = app
+            .execute_contract(
+                Addr::unchecked(ADMIN),
+                contract_addr.clone(),
+                &ExecuteMsg::ScheduleTimelockTransaction {
+                    msgs: vec![],
+                    delay: 3600,
+                    executors: None,
+                    grace_period: None,
+                },
+                &[],
+            )
+            .unwrap();
+        res.events[1]
+            .attributes
+            .iter()
+            .find(|attr| attr.key == "transaction_id")
+            .unwrap()
+            .value
+            .parse::<u64>()
+            .unwrap()
+    };
+
+    let tx1 = schedule(&mut app);
+    let tx2 = schedule(&mut app);
+
+    // The proposer (ADMIN here) can cancel their own pending transaction.
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contract_addr.clone(),
+        &ExecuteMsg::CancelTimelockTransaction { transaction_id: tx1 },
+        &[],
+    )
+    .unwrap();
+
+    // An unrelated account holding neither the proposer role nor ADMIN_ROLE
+    // cannot cancel someone else's transaction.
+    let err = app
+        .execute_contract(
+            Addr::unchecked(UNAUTHORIZED),
+            contract_addr.clone(),
+            &ExecuteMsg::CancelTimelockTransaction { transaction_id: tx2 },
+            &[],
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("MissingRole"));
+
+    // Cancelling an already-cancelled transaction is rejected.
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            contract_addr.clone(),
+            &ExecuteMsg::CancelTimelockTransaction { transaction_id: tx1 },
+            &[],
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("TimelockCancelled"));
+
+    let listed: TimelockTransactionsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            contract_addr.clone(),
+            &QueryMsg::TimelockTransactions { start_after: None, limit: None },
+        )
+        .unwrap();
+    assert_eq!(listed.transactions.len(), 2);
+    assert_eq!(listed.transactions[0].id, tx1);
+    assert_eq!(listed.transactions[0].status, TimelockStatus::Cancelled);
+    assert_eq!(listed.transactions[1].id, tx2);
+    assert_eq!(listed.transactions[1].status, TimelockStatus::Pending);
+
+    // Pagination: start_after the first id returns only the second.
+    let page: TimelockTransactionsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            contract_addr,
+            &QueryMsg::TimelockTransactions { start_after: Some(tx1), limit: Some(1) },
+        )
+        .unwrap();
+    assert_eq!(page.transactions.len(), 1);
+    assert_eq!(page.transactions[0].id, tx2);
+}
+
+#[test]
+fn test_passed_proposal_routes_through_timelock_before_applying() {
+    let mut app = App::default();
+    let code_id = app.store_code(contract_template());
+
+    let contract_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {
+                admin: Some(ADMIN.to_string()),
+                governance_enabled: Some(true),
+                dao_address: None,
+                multisig_config: None,
+                timelock_enabled: Some(true),
+                min_timelock_delay: Some(3600),
+                executor_allowlist: None,
+                registration_fee: None,
+                default_voting_power: Some(1),
+                default_quorum_threshold: Some(1),
+                default_pass_threshold: Some(1),
+                default_quorum: None,
+                default_threshold: None,
+                default_timelock_grace_period: None,
+                voting_period_seconds: None,
+                min_voting_period_seconds: None,
+                proposal_deposit: None,
+                randomness_provider: None,
+                issuer_bond: None,
+                rate_limit: None,
+            },
+            &[],
+            "ZK Verifier",
+            None,
+        )
+        .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contract_addr.clone(),
+        &ExecuteMsg::RegisterCircuit {
+            circuit_id: "test_circuit".to_string(),
+            verification_key: "vk_test_key_12345".to_string(),
+            circuit_type: "groth16".to_string(),
+            nullifier_index: None,
+            commitment_policy: None,
+            revocation_index: None,
+            revocation_witness_index: None,
+            proof_system: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contract_addr.clone(),
+        &ExecuteMsg::GrantRole { role: GOVERNANCE_ROLE.to_string(), account: ADMIN.to_string() },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contract_addr.clone(),
+        &ExecuteMsg::SubmitGovernanceProposal {
+            title: "Deactivate stale circuit".to_string(),
+            description: "timelock routing test".to_string(),
+            proposal_type: ProposalType::DeactivateCircuit { circuit_id: "test_circuit".to_string() },
+            voting_period: None,
+            requested_delay: None,
+            signatories: vec![],
+            instructions: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contract_addr.clone(),
+        &ExecuteMsg::VoteOnProposal { proposal_id: 1, vote: zk_verifier::state::VoteChoice::Yes },
+        &[],
+    )
+    .unwrap();
+
+    app.update_block(|block| block.time = block.time.plus_seconds(7 * 24 * 60 * 60 + 1));
+
+    // Passing the proposal only schedules a timelock transaction; the
+    // circuit stays active until that transaction is later executed.
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contract_addr.clone(),
+        &ExecuteMsg::ExecuteProposal { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(contract_addr.clone(), &QueryMsg::Proposal { proposal_id: 1 })
+        .unwrap();
+    assert!(!proposal.executed);
+    let transaction_id = proposal.scheduled_transaction_id.expect("proposal should be scheduled");
+
+    // The timelock transaction isn't ripe yet.
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            contract_addr.clone(),
+            &ExecuteMsg::ExecuteTimelockTransaction { transaction_id },
+            &[],
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("TimelockNotReady"));
+
+    app.update_block(|block| block.time = block.time.plus_seconds(3600));
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contract_addr.clone(),
+        &ExecuteMsg::ExecuteTimelockTransaction { transaction_id },
+        &[],
+    )
+    .unwrap();
+
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(contract_addr, &QueryMsg::Proposal { proposal_id: 1 })
+        .unwrap();
+    assert!(proposal.executed);
+}
+
+#[test]
+fn test_cancel_scheduled_proposal() {
+    let mut app = App::default();
+    let code_id = app.store_code(contract_template());
+
+    let contract_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {
+                admin: Some(ADMIN.to_string()),
+                governance_enabled: Some(true),
+                dao_address: None,
+                multisig_config: None,
+                timelock_enabled: Some(true),
+                min_timelock_delay: Some(3600),
+                executor_allowlist: None,
+                registration_fee: None,
+                default_voting_power: Some(1),
+                default_quorum_threshold: Some(1),
+                default_pass_threshold: Some(1),
+                default_quorum: None,
+                default_threshold: None,
+                default_timelock_grace_period: None,
+                voting_period_seconds: None,
+                min_voting_period_seconds: None,
+                proposal_deposit: None,
+                randomness_provider: None,
+                issuer_bond: None,
+                rate_limit: None,
+            },
+            &[],
+            "ZK Verifier",
+            None,
+        )
+        .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contract_addr.clone(),
+        &ExecuteMsg::RegisterCircuit {
+            circuit_id: "test_circuit".to_string(),
+            verification_key: "vk_test_key_12345".to_string(),
+            circuit_type: "groth16".to_string(),
+            nullifier_index: None,
+            commitment_policy: None,
+            revocation_index: None,
+            revocation_witness_index: None,
+            proof_system: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contract_addr.clone(),
+        &ExecuteMsg::GrantRole { role: GOVERNANCE_ROLE.to_string(), account: ADMIN.to_string() },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contract_addr.clone(),
+        &ExecuteMsg::SubmitGovernanceProposal {
+            title: "Deactivate stale circuit".to_string(),
+            description: "cancel test".to_string(),
+            proposal_type: ProposalType::DeactivateCircuit { circuit_id: "test_circuit".to_string() },
+            voting_period: None,
+            requested_delay: None,
+            signatories: vec![],
+            instructions: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contract_addr.clone(),
+        &ExecuteMsg::VoteOnProposal { proposal_id: 1, vote: zk_verifier::state::VoteChoice::Yes },
+        &[],
+    )
+    .unwrap();
+
+    app.update_block(|block| block.time = block.time.plus_seconds(7 * 24 * 60 * 60 + 1));
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contract_addr.clone(),
+        &ExecuteMsg::ExecuteProposal { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(contract_addr.clone(), &QueryMsg::Proposal { proposal_id: 1 })
+        .unwrap();
+    let transaction_id = proposal.scheduled_transaction_id.unwrap();
+
+    // ADMIN_ROLE can abort the queued proposal during the delay window.
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contract_addr.clone(),
+        &ExecuteMsg::CancelScheduledProposal { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+
+    app.update_block(|block| block.time = block.time.plus_seconds(3600));
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            contract_addr,
+            &ExecuteMsg::ExecuteTimelockTransaction { transaction_id },
+            &[],
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("TimelockCancelled"));
+}
+
+#[test]
+fn test_requested_delay_extends_past_contract_minimum() {
+    let mut app = App::default();
+    let code_id = app.store_code(contract_template());
+
+    let contract_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {
+                admin: Some(ADMIN.to_string()),
+                governance_enabled: Some(true),
+                dao_address: None,
+                multisig_config: None,
+                timelock_enabled: Some(true),
+                min_timelock_delay: Some(3600),
+                executor_allowlist: None,
+                registration_fee: None,
+                default_voting_power: Some(1),
+                default_quorum_threshold: Some(1),
+                default_pass_threshold: Some(1),
+                default_quorum: None,
+                default_threshold: None,
+                default_timelock_grace_period: None,
+                voting_period_seconds: None,
+                min_voting_period_seconds: None,
+                proposal_deposit: None,
+                randomness_provider: None,
+                issuer_bond: None,
+                rate_limit: None,
+            },
+            &[],
+            "ZK Verifier",
+            None,
+        )
+        .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contract_addr.clone(),
+        &ExecuteMsg::RegisterCircuit {
+            circuit_id: "test_circuit".to_string(),
+            verification_key: "vk_test_key_12345".to_string(),
+            circuit_type: "groth16".to_string(),
+            nullifier_index: None,
+            commitment_policy: None,
+            revocation_index: None,
+            revocation_witness_index: None,
+            proof_system: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contract_addr.clone(),
+        &ExecuteMsg::GrantRole { role: GOVERNANCE_ROLE.to_string(), account: ADMIN.to_string() },
+        &[],
+    )
+    .unwrap();
+
+    // Ask for a 7200s delay, double the contract-wide 3600s minimum.
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contract_addr.clone(),
+        &ExecuteMsg::SubmitGovernanceProposal {
+            title: "Deactivate stale circuit".to_string(),
+            description: "requested_delay test".to_string(),
+            proposal_type: ProposalType::DeactivateCircuit { circuit_id: "test_circuit".to_string() },
+            voting_period: None,
+            requested_delay: Some(7200),
+            signatories: vec![],
+            instructions: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contract_addr.clone(),
+        &ExecuteMsg::VoteOnProposal { proposal_id: 1, vote: zk_verifier::state::VoteChoice::Yes },
+        &[],
+    )
+    .unwrap();
+
+    app.update_block(|block| block.time = block.time.plus_seconds(7 * 24 * 60 * 60 + 1));
+
+    let res = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            contract_addr.clone(),
+            &ExecuteMsg::ExecuteProposal { proposal_id: 1 },
+            &[],
+        )
+        .unwrap();
+    let transaction_id: u64 = res
+        .events
+        .iter()
+        .flat_map(|e| &e.attributes)
+        .find(|a| a.key == "transaction_id")
+        .unwrap()
+        .value
+        .parse()
+        .unwrap();
+
+    // The contract minimum alone has elapsed, but the requested delay hasn't.
+    app.update_block(|block| block.time = block.time.plus_seconds(3600));
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            contract_addr.clone(),
+            &ExecuteMsg::ExecuteTimelockTransaction { transaction_id },
+            &[],
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("TimelockNotReady"));
+
+    app.update_block(|block| block.time = block.time.plus_seconds(3600));
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contract_addr,
+        &ExecuteMsg::ExecuteTimelockTransaction { transaction_id },
+        &[],
+    )
+    .unwrap();
+}
\ No newline at end of file
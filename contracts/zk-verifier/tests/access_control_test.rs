@@ -46,6 +46,11 @@ fn test_unauthorized_circuit_registration_rejection() {
         circuit_id: "test_circuit".to_string(),
         verification_key: "test_vk_12345".to_string(),
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     let err = app
@@ -87,6 +92,11 @@ fn test_authorized_admin_circuit_registration() {
         circuit_id: "admin_circuit".to_string(),
         verification_key: "test_vk_admin_12345".to_string(),
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     let res = app
@@ -150,6 +160,11 @@ fn test_issuer_based_access_control() {
         circuit_id: "issuer_circuit".to_string(),
         verification_key: "test_vk_issuer_12345".to_string(),
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     let res = app
@@ -168,6 +183,11 @@ fn test_issuer_based_access_control() {
         circuit_id: "unauthorized_circuit".to_string(),
         verification_key: "test_vk_unauthorized_12345".to_string(),
         circuit_type: "plonk".to_string(), // Not authorized
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     let err = app
@@ -235,6 +255,11 @@ fn test_deactivated_issuer_rejection() {
         circuit_id: "deactivated_circuit".to_string(),
         verification_key: "test_vk_deactivated_12345".to_string(),
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     let err = app
@@ -410,6 +435,11 @@ fn test_invalid_proof_rejection() {
         circuit_id: "test_circuit".to_string(),
         verification_key: "test_vk_12345".to_string(),
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     app.execute_contract(
@@ -24,6 +24,20 @@ fn proper_instantiate() -> (App, Addr) {
         multisig_config: None,
         timelock_enabled: None,
         min_timelock_delay: None,
+        executor_allowlist: None,
+                registration_fee: None,
+                default_voting_power: None,
+                default_quorum_threshold: None,
+                default_pass_threshold: None,
+                default_quorum: None,
+                default_threshold: None,
+                default_timelock_grace_period: None,
+                voting_period_seconds: None,
+                min_voting_period_seconds: None,
+                proposal_deposit: None,
+                randomness_provider: None,
+                issuer_bond: None,
+                rate_limit: None,
     };
     let contract_addr = app
         .instantiate_contract(
@@ -53,6 +67,11 @@ fn test_age_verification_circuit() {
         circuit_id: "age_verification".to_string(),
         verification_key: "vk_age_verification_12345".to_string(),
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     app.execute_contract(
@@ -117,6 +136,11 @@ fn test_employment_verification_circuit() {
         circuit_id: "employment_verification".to_string(),
         verification_key: "vk_employment_verification_67890".to_string(),
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     app.execute_contract(
@@ -152,6 +176,11 @@ fn test_education_verification_circuit() {
         circuit_id: "education_verification".to_string(),
         verification_key: "vk_education_verification_abcde".to_string(),
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     app.execute_contract(
@@ -187,6 +216,11 @@ fn test_financial_verification_circuit() {
         circuit_id: "financial_verification".to_string(),
         verification_key: "vk_financial_verification_54321".to_string(),
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     app.execute_contract(
@@ -222,6 +256,11 @@ fn test_health_verification_circuit() {
         circuit_id: "health_verification".to_string(),
         verification_key: "vk_health_verification_98765".to_string(),
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     app.execute_contract(
@@ -257,6 +296,11 @@ fn test_location_verification_circuit() {
         circuit_id: "location_verification".to_string(),
         verification_key: "vk_location_verification_fghij".to_string(),
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     app.execute_contract(
@@ -292,6 +336,11 @@ fn test_circuit_deactivation() {
         circuit_id: "test_circuit".to_string(),
         verification_key: "vk_test_12345".to_string(),
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     app.execute_contract(
@@ -346,6 +395,11 @@ fn test_proof_events() {
         circuit_id: "event_test".to_string(),
         verification_key: "vk_event_test_12345".to_string(),
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     app.execute_contract(
@@ -395,6 +449,11 @@ fn test_unauthorized_circuit_deactivation() {
         circuit_id: "test_circuit".to_string(),
         verification_key: "vk_test_12345".to_string(),
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     app.execute_contract(
@@ -51,6 +51,20 @@ fn instantiate_contract(app: &mut App, admin: Option<String>) -> AnyResult<Addr>
         multisig_config: None,
         timelock_enabled: None,
         min_timelock_delay: None,
+        executor_allowlist: None,
+                registration_fee: None,
+                default_voting_power: None,
+                default_quorum_threshold: None,
+                default_pass_threshold: None,
+                default_quorum: None,
+                default_threshold: None,
+                default_timelock_grace_period: None,
+                voting_period_seconds: None,
+                min_voting_period_seconds: None,
+                proposal_deposit: None,
+                randomness_provider: None,
+                issuer_bond: None,
+                rate_limit: None,
     };
 
     app.instantiate_contract(
@@ -91,6 +105,11 @@ fn test_register_circuit() {
         circuit_id: "age_verification".to_string(),
         verification_key: "vk_age_verification_key_12345".to_string(),
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     let _res = app
@@ -131,6 +150,11 @@ fn test_register_duplicate_circuit() {
         circuit_id: "test_circuit".to_string(),
         verification_key: "vk_test_key_12345".to_string(),
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     app.execute_contract(Addr::unchecked(USER), contract_addr.clone(), &msg, &[])
@@ -154,6 +178,11 @@ fn test_register_circuit_empty_id() {
         circuit_id: "".to_string(),
         verification_key: "vk_test_key_12345".to_string(),
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     let err = app
@@ -173,6 +202,11 @@ fn test_register_circuit_invalid_vk() {
         circuit_id: "test_circuit".to_string(),
         verification_key: "".to_string(), // Empty key
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     let err = app
@@ -193,6 +227,11 @@ fn test_submit_valid_proof() {
         circuit_id: "age_verification".to_string(),
         verification_key: "vk_age_verification_key_12345".to_string(),
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     app.execute_contract(Addr::unchecked(USER), contract_addr.clone(), &register_msg, &[])
@@ -248,6 +287,11 @@ fn test_submit_invalid_proof() {
         circuit_id: "test_circuit".to_string(),
         verification_key: "vk_test_key_12345".to_string(),
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     app.execute_contract(Addr::unchecked(USER), contract_addr.clone(), &register_msg, &[])
@@ -312,6 +356,11 @@ fn test_submit_proof_invalid_format() {
         circuit_id: "test_circuit".to_string(),
         verification_key: "vk_test_key_12345".to_string(),
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     app.execute_contract(Addr::unchecked(USER), contract_addr.clone(), &register_msg, &[])
@@ -341,6 +390,11 @@ fn test_deactivate_circuit() {
         circuit_id: "test_circuit".to_string(),
         verification_key: "vk_test_key_12345".to_string(),
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     app.execute_contract(Addr::unchecked(USER), contract_addr.clone(), &register_msg, &[])
@@ -389,6 +443,11 @@ fn test_deactivate_circuit_unauthorized() {
         circuit_id: "test_circuit".to_string(),
         verification_key: "vk_test_key_12345".to_string(),
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     app.execute_contract(Addr::unchecked(USER), contract_addr.clone(), &register_msg, &[])
@@ -441,6 +500,11 @@ fn test_query_circuits() {
             circuit_id: format!("circuit_{}", i),
             verification_key: format!("vk_key_{}", i),
             circuit_type: "groth16".to_string(),
+            nullifier_index: None,
+            commitment_policy: None,
+            revocation_index: None,
+            revocation_witness_index: None,
+            proof_system: None,
         };
 
         app.execute_contract(Addr::unchecked(USER), contract_addr.clone(), &register_msg, &[])
@@ -473,6 +537,11 @@ fn test_query_proofs_by_circuit() {
         circuit_id: "test_circuit".to_string(),
         verification_key: "vk_test_key_12345".to_string(),
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     app.execute_contract(Addr::unchecked(USER), contract_addr.clone(), &register_msg, &[])
@@ -524,6 +593,11 @@ fn test_comprehensive_workflow() {
             circuit_id: circuit_id.to_string(),
             verification_key: vk.to_string(),
             circuit_type: "groth16".to_string(),
+            nullifier_index: None,
+            commitment_policy: None,
+            revocation_index: None,
+            revocation_witness_index: None,
+            proof_system: None,
         };
 
         app.execute_contract(Addr::unchecked(USER), contract_addr.clone(), &register_msg, &[])
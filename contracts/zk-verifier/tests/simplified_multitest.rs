@@ -51,6 +51,20 @@ fn instantiate_contract(app: &mut App, admin: Option<String>) -> AnyResult<Addr>
         multisig_config: None,
         timelock_enabled: Some(false),
         min_timelock_delay: Some(3600),
+        executor_allowlist: None,
+                registration_fee: None,
+                default_voting_power: None,
+                default_quorum_threshold: None,
+                default_pass_threshold: None,
+                default_quorum: None,
+                default_threshold: None,
+                default_timelock_grace_period: None,
+                voting_period_seconds: None,
+                min_voting_period_seconds: None,
+                proposal_deposit: None,
+                randomness_provider: None,
+                issuer_bond: None,
+                rate_limit: None,
     };
 
     app.instantiate_contract(
@@ -74,6 +88,11 @@ fn test_complete_workflow() {
         circuit_id: "age_verification".to_string(),
         verification_key: "vk_age_verification_key_12345".to_string(),
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     let _res = app
@@ -159,6 +178,11 @@ fn test_circuit_deactivation() {
         circuit_id: "test_circuit".to_string(),
         verification_key: "vk_test_key_12345".to_string(),
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     app.execute_contract(Addr::unchecked(USER), contract_addr.clone(), &register_msg, &[])
@@ -208,6 +232,11 @@ fn test_error_conditions() {
         circuit_id: "test_circuit".to_string(),
         verification_key: "vk_test_key_12345".to_string(),
         circuit_type: "groth16".to_string(),
+        nullifier_index: None,
+        commitment_policy: None,
+        revocation_index: None,
+        revocation_witness_index: None,
+        proof_system: None,
     };
 
     app.execute_contract(Addr::unchecked(USER), contract_addr.clone(), &register_msg, &[])
@@ -299,6 +328,11 @@ fn test_multiple_circuits_and_proofs() {
             circuit_id: circuit_id.to_string(),
             verification_key: vk.to_string(),
             circuit_type: "groth16".to_string(),
+            nullifier_index: None,
+            commitment_policy: None,
+            revocation_index: None,
+            revocation_witness_index: None,
+            proof_system: None,
         };
 
         app.execute_contract(Addr::unchecked(USER), contract_addr.clone(), &register_msg, &[])